@@ -1,9 +1,11 @@
 use clap::{App, Arg, ArgMatches};
+use std::fs;
+use std::process;
 use rust_music_theory::chord::Chord;
-use rust_music_theory::note::Notes;
+use rust_music_theory::note::{AccidentalStyle, Note, Notes, Pitch};
 use rust_music_theory::scale::{Direction, Scale};
 
-const AVAILABLE_SCALES: [&str; 9] = [
+const AVAILABLE_SCALES: [&str; 16] = [
     "Major|Ionian",
     "Minor|Aeolian",
     "Dorian",
@@ -13,32 +15,26 @@ const AVAILABLE_SCALES: [&str; 9] = [
     "Locrian",
     "Harmonic Minor",
     "Melodic Minor",
+    "Whole Tone",
+    "Chromatic",
+    "Major Pentatonic",
+    "Minor Pentatonic",
+    "Blues",
+    "Bebop Dominant",
+    "Harmonic Major",
 ];
 
-const AVAILABLE_CHORDS: [&str; 22] = [
-    "Major Triad",
-    "Minor Triad",
-    "Suspended2 Triad",
-    "Suspended4 Triad",
-    "Augmented Triad",
-    "Diminished Triad",
-    "Major Seventh",
-    "Minor Seventh",
-    "Augmented Seventh",
-    "Augmented Major Seventh",
-    "Diminished Seventh",
-    "Half Diminished Seventh",
-    "Minor Major Seventh",
-    "Dominant Seventh",
-    "Dominant Ninth",
-    "Major Ninth",
-    "Dominant Eleventh",
-    "Major Eleventh",
-    "Minor Eleventh",
-    "Dominant Thirteenth",
-    "Major Thirteenth",
-    "Minor Thirteenth",
-];
+/// Separate the words of a variant name, so `HalfDiminished` becomes `Half Diminished`.
+fn spaced(name: &str) -> String {
+    let mut spaced = String::new();
+    for (i, c) in name.char_indices() {
+        if i > 0 && c.is_uppercase() {
+            spaced.push(' ');
+        }
+        spaced.push(c);
+    }
+    spaced
+}
 
 fn scale_command(scale_matches: &ArgMatches) {
     use Direction::*;
@@ -49,18 +45,30 @@ fn scale_command(scale_matches: &ArgMatches) {
                 println!(" - {}", scale);
             }
         }
+        ("midi", Some(midi_matches)) => {
+            let scale = parse_scale(&joined_args(midi_matches), Direction::Ascending);
+            let notes: Vec<Vec<Note>> = scale.notes().into_iter().map(|note| vec![note]).collect();
+            write_midi(midi_matches, &notes);
+        }
         _ => {
-            let scale_args = scale_matches
-                .values_of("args")
-                .unwrap()
-                .collect::<Vec<_>>()
-                .join(" ");
+            let scale_args = joined_args(scale_matches);
 
             let descending = scale_matches.is_present("descending");
             let direction = if descending { Descending } else { Ascending };
 
-            let scale = Scale::from_regex_in_direction(&scale_args, direction).unwrap();
-            scale.print_notes();
+            let mut scale = parse_scale(&scale_args, direction);
+            let octave = octave(scale_matches);
+            scale.octave = octave.unwrap_or(scale.octave);
+            let notes = spelled(scale.notes(), scale_matches);
+            if scale_matches.value_of("format") == Some("json") {
+                print_json(&scale.to_string(), &notes, tuning(scale_matches));
+            } else if scale_matches.is_present("frequency") {
+                print_frequencies(&notes, tuning(scale_matches));
+            } else if octave.is_some() {
+                print_notes_with_octaves(&notes);
+            } else {
+                print_pitches(&notes);
+            }
         }
     }
 }
@@ -69,23 +77,334 @@ fn chord_command(chord_matches: &ArgMatches) {
     match chord_matches.subcommand() {
         ("list", _) => {
             println!("Available chords:");
-            for chord in &AVAILABLE_CHORDS {
-                println!(" - {}", chord);
+            for (quality, number) in Chord::all_supported() {
+                println!(" - {} {}", spaced(&quality.to_string()), spaced(&number.to_string()));
             }
         }
+        ("midi", Some(midi_matches)) => {
+            let chord = parse_chord(&joined_args(midi_matches));
+            write_midi(midi_matches, &[chord.notes()]);
+        }
         _ => {
-            let chord_args = chord_matches
-                .values_of("args")
-                .unwrap()
-                .collect::<Vec<_>>()
-                .join(" ");
-
-            let chord = Chord::from_regex(&chord_args).unwrap();
-            chord.print_notes();
+            let chord_args = joined_args(chord_matches);
+
+            let mut chord = parse_chord(&chord_args);
+            let octave = octave(chord_matches);
+            chord.octave = octave.unwrap_or(chord.octave);
+            let notes = spelled(chord.notes(), chord_matches);
+            if chord_matches.value_of("format") == Some("json") {
+                print_json(&chord.to_string(), &notes, tuning(chord_matches));
+            } else if chord_matches.is_present("frequency") {
+                print_frequencies(&notes, tuning(chord_matches));
+            } else if octave.is_some() {
+                print_notes_with_octaves(&notes);
+            } else {
+                print_pitches(&notes);
+            }
+        }
+    }
+}
+
+fn exit_with_error(message: String) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn parse_chord(string: &str) -> Chord {
+    Chord::from_regex(string).unwrap_or_else(|error| {
+        exit_with_error(format!("Couldn't parse the chord {:?}: {}", string, error))
+    })
+}
+
+fn parse_scale(string: &str, direction: Direction) -> Scale {
+    Scale::from_regex_in_direction(string, direction).unwrap_or_else(|error| {
+        exit_with_error(format!("Couldn't parse the scale {:?}: {}", string, error))
+    })
+}
+
+fn parse_value<T: std::str::FromStr>(matches: &ArgMatches, name: &str) -> T {
+    let value = matches.value_of(name).unwrap();
+    value
+        .parse()
+        .unwrap_or_else(|_| exit_with_error(format!("Invalid {} {:?}", name, value)))
+}
+
+fn progression_command(progression_matches: &ArgMatches) {
+    let progression = joined_args(progression_matches);
+
+    // chords may be separated by commas or bars to allow long forms like `C major, G major`
+    let tokens: Vec<&str> = if progression.contains([',', '|']) {
+        progression.split([',', '|']).map(str::trim).collect()
+    } else {
+        progression.split_whitespace().collect()
+    };
+
+    let key = progression_matches.value_of("key").map(|key| {
+        Scale::from_regex(key).unwrap_or_else(|error| {
+            exit_with_error(format!("Couldn't parse the key {:?}: {}", key, error))
+        })
+    });
+
+    let mut chords = Vec::new();
+    for token in tokens.iter().filter(|token| !token.is_empty()) {
+        let chord = match &key {
+            Some(key) => key.chord_from_numeral(token).map_err(|error| error.to_string()),
+            None => Chord::from_regex(token).map_err(|error| error.to_string()),
+        };
+
+        match chord {
+            Ok(chord) => chords.push(chord),
+            Err(error) => exit_with_error(format!("Couldn't parse the chord {:?}: {}", token, error)),
+        }
+    }
+
+    for chord in &chords {
+        println!("{}:", chord);
+        for (i, note) in chord.notes().iter().enumerate() {
+            println!("  {}: {}", i + 1, note.pitch);
         }
     }
 }
 
+fn identify_command(identify_matches: &ArgMatches) {
+    let args = joined_args(identify_matches);
+
+    // notes without an octave are placed above the note before them, starting in octave 4
+    let mut notes: Vec<Note> = Vec::new();
+    for token in args.split_whitespace() {
+        let (name, octave) = match token.chars().last().and_then(|c| c.to_digit(10)) {
+            Some(octave) => (&token[..token.len() - 1], Some(octave as u8)),
+            None => (token, None),
+        };
+        let pitch: Pitch = name.parse().unwrap_or_else(|error| {
+            exit_with_error(format!("Couldn't parse the note {:?}: {}", token, error))
+        });
+
+        let note = match (octave, notes.last()) {
+            (Some(octave), _) => Note::new(pitch, octave),
+            (None, Some(previous)) => {
+                let note = Note::new(pitch, previous.octave);
+                if note.to_midi() > previous.to_midi() {
+                    note
+                } else {
+                    Note::new(pitch, previous.octave.saturating_add(1))
+                }
+            }
+            (None, None) => Note::new(pitch, 4),
+        };
+        notes.push(note);
+    }
+
+    let chord = Chord::from_notes(&notes)
+        .unwrap_or_else(|_| exit_with_error(format!("No chord matches the notes {:?}", args)));
+    let bass = chord.bass_note().pitch;
+    let quality = spaced(&chord.quality.to_string());
+    let name = format!("{} {} {}", chord.root, quality, spaced(&chord.number.to_string()));
+    if bass == chord.root {
+        println!("{}", name);
+    } else {
+        println!("{} / {}", name, bass);
+    }
+}
+
+fn joined_args(matches: &ArgMatches) -> String {
+    matches
+        .values_of("args")
+        .map(|args| args.collect::<Vec<_>>().join(" "))
+        .unwrap_or_default()
+}
+
+const TICKS_PER_BEAT: u16 = 480;
+
+fn variable_length(mut value: u32, bytes: &mut Vec<u8>) {
+    let mut buffer = vec![(value & 0x7f) as u8];
+    value >>= 7;
+    while value > 0 {
+        buffer.push((value & 0x7f) as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.extend(buffer.iter().rev());
+}
+
+/// Build a single track Standard MIDI File playing each group of notes in turn.
+fn midi_file(groups: &[Vec<Note>], tempo: u32, ticks: u32) -> Vec<u8> {
+    let mut track = vec![];
+    let microseconds = 60_000_000 / tempo.max(1);
+    track.extend(&[0x00, 0xff, 0x51, 0x03]);
+    track.extend(&microseconds.to_be_bytes()[1..]);
+
+    for group in groups {
+        for note in group {
+            track.extend(&[0x00, 0x90, note.to_midi(), 0x64]);
+        }
+        for (i, note) in group.iter().enumerate() {
+            variable_length(if i == 0 { ticks } else { 0 }, &mut track);
+            track.extend(&[0x80, note.to_midi(), 0x00]);
+        }
+    }
+    track.extend(&[0x00, 0xff, 0x2f, 0x00]);
+
+    let mut bytes = b"MThd".to_vec();
+    bytes.extend(&6u32.to_be_bytes());
+    bytes.extend(&0u16.to_be_bytes());
+    bytes.extend(&1u16.to_be_bytes());
+    bytes.extend(&TICKS_PER_BEAT.to_be_bytes());
+    bytes.extend(b"MTrk");
+    bytes.extend(&(track.len() as u32).to_be_bytes());
+    bytes.extend(track);
+    bytes
+}
+
+fn write_midi(matches: &ArgMatches, groups: &[Vec<Note>]) {
+    let tempo: u32 = parse_value(matches, "tempo");
+    let beats: f64 = parse_value(matches, "duration");
+    let ticks = (beats * TICKS_PER_BEAT as f64).round() as u32;
+
+    let output = matches.value_of("output").unwrap();
+    fs::write(output, midi_file(groups, tempo, ticks))
+        .unwrap_or_else(|error| exit_with_error(format!("Couldn't write {:?}: {}", output, error)));
+}
+
+fn midi_command(about: &'static str) -> App<'static, 'static> {
+    App::new("midi")
+        .about(about)
+        .arg(Arg::with_name("args").required(true).multiple(true))
+        .arg(
+            Arg::with_name("output")
+                .help("the MIDI file to write")
+                .short("o")
+                .long("output")
+                .takes_value(true)
+                .required(true),
+        )
+        .arg(
+            Arg::with_name("tempo")
+                .help("tempo in beats per minute")
+                .long("tempo")
+                .takes_value(true)
+                .default_value("120"),
+        )
+        .arg(
+            Arg::with_name("duration")
+                .help("length of each note in beats")
+                .long("duration")
+                .takes_value(true)
+                .default_value("1"),
+        )
+}
+
+fn json_string(string: &str) -> String {
+    format!("\"{}\"", string.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn print_json(name: &str, notes: &[Note], a4_hz: f64) {
+    let notes = notes
+        .iter()
+        .map(|note| {
+            format!(
+                "{{\"pitch\":{},\"octave\":{},\"frequency\":{}}}",
+                json_string(&note.pitch.to_string()),
+                note.octave,
+                note.to_frequency_with_reference(a4_hz)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    println!("{{\"name\":{},\"notes\":[{}]}}", json_string(name), notes);
+}
+
+fn print_frequencies(notes: &[Note], a4_hz: f64) {
+    println!("Notes:");
+    for (i, note) in notes.iter().enumerate() {
+        println!(
+            "  {}: {} {:.2} Hz",
+            i + 1,
+            note.pitch,
+            note.to_frequency_with_reference(a4_hz)
+        );
+    }
+}
+
+fn print_pitches(notes: &[Note]) {
+    println!("Notes:");
+    for (i, note) in notes.iter().enumerate() {
+        println!("  {}: {}", i + 1, note.pitch);
+    }
+}
+
+fn print_notes_with_octaves(notes: &[Note]) {
+    println!("Notes:");
+    for (i, note) in notes.iter().enumerate() {
+        println!("  {}: {}", i + 1, note);
+    }
+}
+
+fn tuning(matches: &ArgMatches) -> f64 {
+    parse_value(matches, "tuning")
+}
+
+/// Respell the notes with the accidentals given with `--spell`, if any.
+fn spelled(notes: Vec<Note>, matches: &ArgMatches) -> Vec<Note> {
+    let style = match matches.value_of("spell") {
+        Some("flat") => AccidentalStyle::Flat,
+        Some("sharp") => AccidentalStyle::Sharp,
+        _ => return notes,
+    };
+    notes.iter().map(|note| note.with_style(style)).collect()
+}
+
+const MAX_OCTAVE: u8 = 8;
+
+/// Get the starting octave given with `--octave`, if any.
+fn octave(matches: &ArgMatches) -> Option<u8> {
+    matches.value_of("octave")?;
+    let octave: u8 = parse_value(matches, "octave");
+    if octave > MAX_OCTAVE {
+        exit_with_error(format!("Invalid octave {:?}, the octave must be at most {}", octave, MAX_OCTAVE));
+    }
+    Some(octave)
+}
+
+fn frequency_arg() -> Arg<'static, 'static> {
+    Arg::with_name("frequency")
+        .help("print the frequency of each note")
+        .short("f")
+        .long("frequency")
+}
+
+fn tuning_arg() -> Arg<'static, 'static> {
+    Arg::with_name("tuning")
+        .help("frequency of A4 in Hz")
+        .long("tuning")
+        .takes_value(true)
+        .default_value("440")
+}
+
+fn octave_arg() -> Arg<'static, 'static> {
+    Arg::with_name("octave")
+        .help("the octave to start from, 4 unless given")
+        .long("octave")
+        .takes_value(true)
+}
+
+fn spell_arg() -> Arg<'static, 'static> {
+    Arg::with_name("spell")
+        .help("spell black keys with flats or sharps instead of as the key spells them")
+        .long("spell")
+        .takes_value(true)
+        .possible_values(&["flat", "sharp"])
+}
+
+fn format_arg() -> Arg<'static, 'static> {
+    Arg::with_name("format")
+        .help("output format")
+        .long("format")
+        .takes_value(true)
+        .possible_values(&["text", "json"])
+        .default_value("text")
+}
+
 fn main() {
     let matches = App::new("RustMusicTheory")
         .version("0.1")
@@ -95,6 +414,7 @@ fn main() {
             App::new("scale")
                 .about("Provides information for the specified scale")
                 .subcommand(App::new("list").about("Prints out the available scales"))
+                .subcommand(midi_command("Writes a MIDI file playing the scale"))
                 .arg(
                     Arg::with_name("args")
                         .help("scale args, examples:\nC melodic minor\nD# dorian")
@@ -105,16 +425,54 @@ fn main() {
                         .help("list scale in descending order")
                         .short("d")
                         .long("descending"),
-                ),
+                )
+                .arg(frequency_arg())
+                .arg(tuning_arg())
+                .arg(octave_arg())
+                .arg(spell_arg())
+                .arg(format_arg()),
         )
         .subcommand(
             App::new("chord")
                 .about("Provides information for the specified chord")
                 .subcommand(App::new("list").about("Prints out the available chords"))
+                .subcommand(midi_command("Writes a MIDI file playing the chord"))
                 .arg(
                     Arg::with_name("args")
                         .help("chord args, examples:\nC minor\nAb augmented major seventh\nF# dominant seventh / C#\nC/1")
                         .multiple(true),
+                )
+                .arg(frequency_arg())
+                .arg(tuning_arg())
+                .arg(octave_arg())
+                .arg(spell_arg())
+                .arg(format_arg()),
+        )
+        .subcommand(
+            App::new("progression")
+                .about("Provides the notes of each chord in a progression")
+                .arg(
+                    Arg::with_name("args")
+                        .help("chords separated by spaces, commas or bars, examples:\nC G Am F\nC major, G major\nI V vi IV (with --key)")
+                        .required(true)
+                        .multiple(true),
+                )
+                .arg(
+                    Arg::with_name("key")
+                        .help("read the chords as Roman numerals in a key, like \"C major\"")
+                        .short("k")
+                        .long("key")
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            App::new("identify")
+                .about("Names the chord formed by the given notes")
+                .arg(
+                    Arg::with_name("args")
+                        .help("notes separated by spaces, from the lowest, examples:\nC E G\nC Eb Gb A\nE3 C4 G4")
+                        .required(true)
+                        .multiple(true),
                 ),
         )
         .get_matches();
@@ -128,6 +486,14 @@ fn main() {
             chord_command(chord_matches);
         }
 
+        ("progression", Some(progression_matches)) => {
+            progression_command(progression_matches);
+        }
+
+        ("identify", Some(identify_matches)) => {
+            identify_command(identify_matches);
+        }
+
         _ => println!("Please use the help command to see the available commands"),
     }
 }
@@ -1,10 +1,16 @@
 //! Chords.
 
+mod builder;
+#[allow(clippy::module_inception)]
 mod chord;
 mod errors;
 mod number;
+mod progression;
 mod quality;
 
-pub use chord::Chord;
+pub use builder::ChordBuilder;
+pub use chord::{ArpPattern, Chord, ToneRole};
+pub use errors::ChordError;
 pub use number::Number;
+pub use progression::Progression;
 pub use quality::Quality;
@@ -0,0 +1,73 @@
+use crate::chord::{Chord, ChordError, Number, Quality};
+use crate::note::{NoteLetter, Pitch};
+
+/// A builder for chords that need more than a root, quality and number.
+#[derive(Debug, Clone)]
+pub struct ChordBuilder {
+    root: Pitch,
+    quality: Quality,
+    number: Number,
+    inversion: u8,
+    octave: u8,
+}
+
+impl ChordBuilder {
+    /// Create a new builder for a C major triad in root position in octave 4.
+    pub fn new() -> Self {
+        ChordBuilder {
+            root: Pitch::new(NoteLetter::C, 0),
+            quality: Quality::Major,
+            number: Number::Triad,
+            inversion: 0,
+            octave: 4,
+        }
+    }
+
+    /// Set the root of the chord.
+    pub fn root(mut self, root: Pitch) -> Self {
+        self.root = root;
+        self
+    }
+
+    /// Set the quality of the chord.
+    pub fn quality(mut self, quality: Quality) -> Self {
+        self.quality = quality;
+        self
+    }
+
+    /// Set the number of the chord.
+    pub fn number(mut self, number: Number) -> Self {
+        self.number = number;
+        self
+    }
+
+    /// Set the inversion of the chord: 0=root position, 1=first inversion, etc.
+    pub fn inversion(mut self, inversion: u8) -> Self {
+        self.inversion = inversion;
+        self
+    }
+
+    /// Set the octave of the root of the chord.
+    pub fn octave(mut self, octave: u8) -> Self {
+        self.octave = octave;
+        self
+    }
+
+    /// Build the chord.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChordError::InvalidInversion`] if the inversion is greater than the number of
+    /// intervals in the chord.
+    pub fn build(self) -> Result<Chord, ChordError> {
+        let mut chord = Chord::try_with_inversion(self.root, self.quality, self.number, self.inversion)?;
+        chord.octave = self.octave;
+        Ok(chord)
+    }
+}
+
+impl Default for ChordBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
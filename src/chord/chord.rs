@@ -2,10 +2,90 @@ use crate::chord::errors::ChordError;
 use crate::chord::number::Number::Triad;
 use crate::chord::{Number, Quality};
 use crate::interval::Interval;
-use crate::note::{Note, NoteError, Notes, Pitch, NoteLetter};
+#[cfg(feature = "std")]
+use crate::note::NoteError;
+use crate::note::{Note, Notes, Pitch, NoteLetter};
+use crate::scale::Scale;
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::Formatter;
+#[cfg(feature = "std")]
+use core::convert::TryFrom;
+#[cfg(feature = "std")]
+use core::str::FromStr;
+use strum_macros::Display;
+
+/// The semitones between consecutive notes of each known chord in root position.
+const CHORD_PATTERNS: [(Quality, Number, &[u8]); 31] = {
+    use Number::*;
+    use Quality::*;
+    [
+        (Major, Triad, &[4, 3]),
+        (Minor, Triad, &[3, 4]),
+        (Suspended2, Triad, &[2, 5]),
+        (Suspended4, Triad, &[5, 2]),
+        (Augmented, Triad, &[4, 4]),
+        (Diminished, Triad, &[3, 3]),
+        (Major, Fifth, &[7]),
+        (Major, Sixth, &[4, 3, 2]),
+        (Minor, Sixth, &[3, 4, 2]),
+        (Major, Seventh, &[4, 3, 4]),
+        (Minor, Seventh, &[3, 4, 3]),
+        (Augmented, Seventh, &[4, 4, 2]),
+        (Augmented, MajorSeventh, &[4, 4, 3]),
+        (Diminished, Seventh, &[3, 3, 3]),
+        (HalfDiminished, Seventh, &[3, 3, 4]),
+        (Minor, MajorSeventh, &[3, 4, 4]),
+        (Dominant, Seventh, &[4, 3, 3]),
+        (Dominant, Ninth, &[4, 3, 3, 4]),
+        (Major, Ninth, &[4, 3, 4, 3]),
+        (Dominant, Eleventh, &[4, 3, 3, 4, 4]),
+        (Major, Eleventh, &[4, 3, 4, 3, 3]),
+        (Minor, Eleventh, &[3, 4, 3, 4, 3]),
+        (Dominant, Thirteenth, &[4, 3, 3, 4, 3, 4]),
+        (Major, Thirteenth, &[4, 3, 4, 3, 3, 4]),
+        (Minor, Thirteenth, &[3, 4, 3, 4, 3, 4]),
+        (Major, Add9, &[4, 3, 7]),
+        (Minor, Add9, &[3, 4, 7]),
+        (Major, Add11, &[4, 3, 10]),
+        (Minor, Add11, &[3, 4, 10]),
+        // the thirteenth is 14 semitones above the fifth, a major thirteenth above the root
+        (Major, Add13, &[4, 3, 14]),
+        (Minor, Add13, &[3, 4, 14]),
+    ]
+};
+
+/// The role of a melody note over a chord.
+#[derive(Display, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ToneRole {
+    /// A note of the chord.
+    ChordTone,
+    /// A note that extends the chord, like a ninth, eleventh or thirteenth.
+    Tension,
+    /// A note that clashes with the chord, like the fourth over a major chord.
+    NonChordTone,
+}
+
+/// The order that `Chord::arpeggiate` plays the notes of a chord in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ArpPattern {
+    /// From the lowest note to the highest.
+    Up,
+    /// From the highest note to the lowest.
+    Down,
+    /// Up and back down, without repeating the highest and lowest notes, so the pattern can loop.
+    UpDown,
+    /// Each note once in a shuffled order, which is the same for the same seed.
+    Random(u64),
+}
 
 /// A chord.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Chord {
     /// The root note of the chord.
     pub root: Pitch,
@@ -19,39 +99,535 @@ pub struct Chord {
     pub number: Number,
     /// The inversion of the chord: 0=root position, 1=first inversion, etc.
     pub inversion: u8,
+    /// A bass note outside the chord, played below it as in slash chords like `F/G`.
+    pub bass: Option<Pitch>,
 }
 
 impl Chord {
     /// Create a new chord.
-    pub fn new(root: Pitch, quality: Quality, number: Number) -> Self {
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChordError::UnknownChord`] if the quality and number don't form a known chord,
+    /// like a suspended second thirteenth.
+    pub fn new(root: Pitch, quality: Quality, number: Number) -> Result<Self, ChordError> {
         Self::with_inversion(root, quality, number, 0)
     }
 
-    /// Create a new chord with a given inversion.
+    /// Create a new chord with a given inversion, wrapping around past the last inversion.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChordError::UnknownChord`] if the quality and number don't form a known chord.
     pub fn with_inversion(
         root: Pitch,
         quality: Quality,
         number: Number,
         inversion: u8,
-    ) -> Self {
-        let intervals = Self::chord_intervals(quality, number);
+    ) -> Result<Self, ChordError> {
+        let intervals = Self::chord_intervals(quality, number)?;
         let inversion = inversion % (intervals.len() + 1) as u8;
-        Chord {
+        Ok(Chord {
             root,
             octave: 4,
             intervals,
             quality,
             number,
             inversion,
+            bass: None,
+        })
+    }
+
+    /// Create a new chord with a given inversion, failing past the last inversion.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChordError::InvalidInversion`] if `inversion` is greater than the number of
+    /// intervals in the chord, or [`ChordError::UnknownChord`] if the quality and number don't
+    /// form a known chord.
+    pub fn try_with_inversion(
+        root: Pitch,
+        quality: Quality,
+        number: Number,
+        inversion: u8,
+    ) -> Result<Self, ChordError> {
+        let max = Self::chord_intervals(quality, number)?.len() as u8;
+        if inversion > max {
+            return Err(ChordError::InvalidInversion {
+                requested: inversion,
+                max,
+            });
+        }
+
+        Self::with_inversion(root, quality, number, inversion)
+    }
+
+    /// Create a new chord in root position with its root in the given octave.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChordError::UnknownChord`] if the quality and number don't form a known chord.
+    pub fn with_octave(
+        root: Pitch,
+        quality: Quality,
+        number: Number,
+        octave: u8,
+    ) -> Result<Self, ChordError> {
+        let chord = Self::new(root, quality, number)?;
+        Ok(Chord { octave, ..chord })
+    }
+
+    /// Iterate over the notes of the chord lazily, in the same order and octaves as `notes`.
+    pub fn note_iter(&self) -> impl Iterator<Item = Note> + '_ {
+        let len = self.intervals.len() + 1;
+        let inversion = self.inversion as usize % len;
+        let root = Note::new(self.root, self.octave);
+        let root_semitones = root.semitones();
+
+        let tone = move |index: usize| {
+            if index == 0 {
+                return root.clone();
+            }
+            let intervals = &self.intervals[..index];
+            let semitones: i32 = intervals.iter().map(|interval| interval.semitone_count as i32).sum();
+            let steps: i32 = if self.is_tertian() {
+                index as i32 * 2
+            } else {
+                intervals.iter().map(|interval| interval.number as i32).sum()
+            };
+
+            // spell each tone by its degree above the root, so Cm has Eb rather than D#
+            Note::from_semitones(root_semitones + semitones).respell(self.root.letter.offset(steps))
+        };
+
+        // Normalize to the correct octave
+        let diff = tone(inversion).octave.saturating_sub(self.octave);
+
+        let bass = self.bass.map(|pitch| {
+            let mut lowest = tone(inversion);
+            lowest.octave = lowest.octave.saturating_sub(diff);
+            let mut bass = Note::new(pitch, lowest.octave);
+            if bass.semitones() >= lowest.semitones() {
+                bass.octave = bass.octave.saturating_sub(1);
+            }
+            bass
+        });
+
+        let tones = (0..len).scan(None, move |previous: &mut Option<i32>, i| {
+            let mut note = tone((i + inversion) % len);
+            note.octave = note.octave.saturating_sub(diff);
+
+            // Raise each tone by whole octaves until it is above the previous one, comparing
+            // absolute pitches so that tones spanning more than an octave stay in order. Tones
+            // that would go past the highest octave stay in it.
+            if let Some(previous) = *previous {
+                while note.semitones() <= previous && note.octave < u8::MAX {
+                    note.octave = note.octave.saturating_add(1);
+                }
+            }
+
+            *previous = Some(note.semitones());
+            Some(note)
+        });
+
+        bass.into_iter().chain(tones)
+    }
+
+    /// Get the lowest sounding note of the chord, including a bass note outside it.
+    pub fn bass_note(&self) -> Note {
+        self.note_iter()
+            .next()
+            .unwrap_or_else(|| Note::new(self.root, self.octave))
+    }
+
+    /// Get the root of the chord in the octave it sounds in, whatever the inversion.
+    pub fn root_note(&self) -> Note {
+        let len = self.intervals.len() + 1;
+        let position = (len - self.inversion as usize % len) % len + self.bass.is_some() as usize;
+        self.note_iter()
+            .nth(position)
+            .unwrap_or_else(|| Note::new(self.root, self.octave))
+    }
+
+    /// Get the pitch classes this chord shares with the other, in the order of this chord.
+    pub fn common_tones(&self, other: &Chord) -> Vec<Pitch> {
+        let mut common: Vec<Pitch> = Vec::new();
+        for note in self.note_iter() {
+            let class = note.pitch.into_u8();
+            let shared = other.note_iter().any(|other| other.pitch.into_u8() == class);
+            if shared && common.iter().all(|pitch| pitch.into_u8() != class) {
+                common.push(note.pitch);
+            }
+        }
+        common
+    }
+
+    /// Check whether a pitch class is in the chord in any octave, unlike `contains_note`.
+    pub fn contains_pitch_class(&self, pitch: Pitch) -> bool {
+        self.note_iter().any(|note| note.pitch.into_u8() == pitch.into_u8())
+    }
+
+    /// Check whether a note is in this voicing of the chord in the same octave.
+    pub fn contains_note(&self, note: &Note) -> bool {
+        self.note_iter().any(|chord_note| chord_note.semitones() == note.semitones())
+    }
+
+    /// Get the fewest total semitones the voices must move to get from this chord to the other.
+    pub fn voice_leading_distance(&self, other: &Chord) -> u32 {
+        let classes = |chord: &Chord| {
+            let mut classes: Vec<u8> = chord.note_iter().map(|note| note.pitch.into_u8()).collect();
+            classes.sort_unstable();
+            classes.dedup();
+            classes
+        };
+        let (from, to) = (classes(self), classes(other));
+        let (larger, smaller) = if from.len() >= to.len() { (from, to) } else { (to, from) };
+        if smaller.is_empty() {
+            return 0;
+        }
+
+        let distance = |a: u8, b: u8| {
+            let semitones = (a as i32 - b as i32).rem_euclid(12) as u32;
+            semitones.min(12 - semitones)
+        };
+
+        // The cheapest way to move the voices of the larger chord seen so far onto each subset
+        // of the smaller chord's pitch classes
+        let full = (1usize << smaller.len()) - 1;
+        let mut costs = vec![u32::MAX; full + 1];
+        costs[0] = 0;
+        for &voice in &larger {
+            let mut next = vec![u32::MAX; full + 1];
+            for (used, &cost) in costs.iter().enumerate().filter(|(_, &cost)| cost != u32::MAX) {
+                for (target, &class) in smaller.iter().enumerate() {
+                    let reached = used | 1 << target;
+                    next[reached] = next[reached].min(cost + distance(voice, class));
+                }
+            }
+            costs = next;
+        }
+
+        costs[full]
+    }
+
+    /// Classify a melody note as a chord tone, a tension or a non-chord tone over the chord.
+    pub fn classify_tone(&self, pitch: Pitch) -> ToneRole {
+        let classes: Vec<u8> = self.note_iter().map(|note| note.pitch.into_u8()).collect();
+        let class = pitch.into_u8();
+        let below = |semitones: u8| classes.contains(&((class + 12 - semitones) % 12));
+
+        if classes.contains(&class) {
+            return ToneRole::ChordTone;
+        }
+
+        let above_root = (class + 12 - self.root.into_u8()) % 12;
+        let altered = self.quality == Quality::Dominant && [1, 3, 6, 8].contains(&above_root);
+        if altered || (below(2) && !below(1)) {
+            ToneRole::Tension
+        } else {
+            ToneRole::NonChordTone
+        }
+    }
+
+    /// Get the negative harmony equivalent of the chord, reflected around the axis of a key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChordError::UnknownChord`] if the reflected notes don't form a known chord,
+    /// which can happen when they are squeezed into the highest octave.
+    pub fn negative(&self, key: Pitch) -> Result<Chord, ChordError> {
+        let reflect = |pitch: Pitch| {
+            let letter = NoteLetter::C.offset(2 * key.letter as i32 + 4 - pitch.letter as i32);
+            let semitones = (2 * key.into_u8() as i32 + 7 - pitch.into_u8() as i32).rem_euclid(12);
+            let mut accidental = (semitones - letter.semitones() as i32).rem_euclid(12);
+            if accidental > 6 {
+                accidental -= 12;
+            }
+            Pitch::new(letter, accidental as i8)
+        };
+
+        // place the reflected notes from the highest note of the chord down, in ascending order
+        let voicing = |chord: &Chord| {
+            let mut notes: Vec<Note> = Vec::new();
+            for note in chord.notes().iter().rev() {
+                let pitch = reflect(note.pitch);
+                let note = match notes.last() {
+                    Some(previous) if Note::new(pitch, previous.octave) <= *previous => {
+                        Note::new(pitch, previous.octave.saturating_add(1))
+                    }
+                    Some(previous) => Note::new(pitch, previous.octave),
+                    None => Note::new(pitch, self.octave),
+                };
+                notes.push(note);
+            }
+            notes
+        };
+
+        // a bass note outside the chord can leave the reflected notes unrecognized, so fall back
+        // to the chord without it
+        Chord::from_notes(&voicing(self))
+            .or_else(|_| Chord::from_notes(&voicing(&Chord { bass: None, ..self.clone() })))
+    }
+
+    /// Check whether every note of the chord, including a bass note outside it, is in the scale.
+    pub fn is_diatonic_to(&self, scale: &Scale) -> bool {
+        let pitches: Vec<Pitch> = self.note_iter().map(|note| note.pitch).collect();
+        scale.contains_all(&pitches)
+    }
+
+    /// Get the conventional resolution of the chord within a key, if it has a strong tendency.
+    pub fn resolve(&self, key: &Scale) -> Option<Chord> {
+        use Quality::*;
+        let diatonic = self.is_diatonic_to(key);
+        let position = (self.root.into_u8() + 12 - key.tonic.into_u8()) % 12;
+
+        let target = match self.quality {
+            Dominant => position + 5,
+            Major if self.number == Triad && (position == 7 || !diatonic) => position + 5,
+            Diminished | HalfDiminished if position == 11 || !diatonic => position + 1,
+            _ => return None,
+        } % 12;
+
+        let degree = key
+            .chromatic_positions()
+            .iter()
+            .position(|&position| position == target)?;
+        let mut chord = key.stacked_chord(degree, 3)?;
+        if chord.quality != Major && chord.quality != Minor {
+            return None;
+        }
+
+        chord.octave = self.octave;
+        Some(chord)
+    }
+
+    /// Transpose the chord up or down by a number of semitones, keeping its quality and inversion.
+    pub fn transpose(&self, semitones: i8) -> Chord {
+        let root = Note::new(self.root, self.octave).transpose(semitones as i32);
+        let bass = self
+            .bass
+            .map(|bass| Note::new(bass, self.octave).transpose(semitones as i32).pitch);
+        Chord {
+            root: root.pitch,
+            octave: root.octave,
+            bass,
+            ..self.clone()
+        }
+    }
+
+    /// Get the same chord with a different quality, keeping everything else.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChordError::UnknownChord`] if the quality and number don't form a known chord.
+    pub fn with_quality(&self, quality: Quality) -> Result<Chord, ChordError> {
+        let chord = Self::with_inversion(self.root, quality, self.number, self.inversion)?;
+        Ok(Chord {
+            octave: self.octave,
+            bass: self.bass,
+            ..chord
+        })
+    }
+
+    /// Get the major chord with the same root and number, like Cmaj7 from Cm7.
+    pub fn to_major(&self) -> Result<Chord, ChordError> {
+        self.with_quality(Quality::Major)
+    }
+
+    /// Get the minor chord with the same root and number, like Cm from C.
+    pub fn to_minor(&self) -> Result<Chord, ChordError> {
+        self.with_quality(Quality::Minor)
+    }
+
+    /// Get the dominant chord with the same root and number, like C7 from Cmaj7.
+    pub fn to_dominant(&self) -> Result<Chord, ChordError> {
+        self.with_quality(Quality::Dominant)
+    }
+
+    /// Get the augmented chord with the same root and number, like C+ from C.
+    pub fn augment(&self) -> Result<Chord, ChordError> {
+        self.with_quality(Quality::Augmented)
+    }
+
+    /// Get the diminished chord with the same root and number, like C° from C.
+    pub fn diminish(&self) -> Result<Chord, ChordError> {
+        self.with_quality(Quality::Diminished)
+    }
+
+    /// Get the interval from the root to each note of the chord in root position, as spelled.
+    pub fn intervals_from_root(&self) -> Vec<Interval> {
+        let root_position = Chord {
+            bass: None,
+            ..self.root_position()
+        };
+        let root = Note::new(self.root, self.octave);
+        let mut semitones = 0;
+
+        root_position
+            .note_iter()
+            .enumerate()
+            .map(|(i, note)| {
+                if i > 0 {
+                    semitones += self.intervals[i - 1].semitone_count;
+                }
+                Interval::from_notes(&root, &note).unwrap_or_else(|_| {
+                    Interval::from_semitone(semitones).unwrap_or_default()
+                })
+            })
+            .collect()
+    }
+
+    /// Get the same chord in root position, keeping a bass note outside the chord.
+    pub fn root_position(&self) -> Chord {
+        Chord {
+            inversion: 0,
+            ..self.clone()
+        }
+    }
+
+    /// Get the next inversion of the chord, wrapping around to root position after the last.
+    pub fn invert(&self) -> Chord {
+        let len = self.intervals.len() as u8 + 1;
+        Chord {
+            inversion: (self.inversion % len + 1) % len,
+            ..self.clone()
+        }
+    }
+
+    /// Invert the chord so that the given pitch is its lowest note.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChordError::BassNotInChord`] if the pitch is not a note of the chord, leaving
+    /// the chord unchanged.
+    pub fn invert_to_bass(&mut self, bass: Pitch) -> Result<(), ChordError> {
+        let root_position = Chord {
+            inversion: 0,
+            bass: None,
+            ..self.clone()
+        };
+        let inversion = root_position
+            .note_iter()
+            .position(|note| note.pitch.into_u8() == bass.into_u8())
+            .ok_or(ChordError::BassNotInChord(bass))?;
+
+        self.inversion = inversion as u8;
+        self.bass = None;
+        Ok(())
+    }
+
+    /// Get the number of distinct voices in the chord, including a bass note outside it.
+    pub fn voice_count(&self) -> usize {
+        self.intervals.len() + 1 + self.bass.is_some() as usize
+    }
+
+    /// Get the ascending notes of the chord with a 1-based degree doubled at an octave offset.
+    pub fn double(&self, degree: u8, octave_offset: i8) -> Vec<Note> {
+        let mut notes = self.notes();
+        let tones = self.intervals.len() + 1;
+        if degree == 0 || degree as usize > tones {
+            return notes;
         }
+
+        let bass = self.bass.is_some() as usize;
+        let inversion = self.inversion as usize % tones;
+        let position = (degree as usize - 1 + tones - inversion) % tones + bass;
+        let tone = &notes[position];
+        let octave = (tone.octave as i16 + octave_offset as i16).clamp(0, u8::MAX as i16) as u8;
+        let doubled = Note::new(tone.pitch, octave);
+
+        let index = notes
+            .iter()
+            .position(|note| note.semitones() > doubled.semitones())
+            .unwrap_or(notes.len());
+        notes.insert(index, doubled);
+        notes
+    }
+
+    /// Get the notes of the chord paired with a MIDI velocity, clamped to 127.
+    pub fn notes_with_velocity(&self, base: u8) -> Vec<(Note, u8)> {
+        self.notes_with_accented_bass(base, 0)
+    }
+
+    /// Get the notes of the chord paired with MIDI velocities, with the lowest note accented.
+    pub fn notes_with_accented_bass(&self, base: u8, accent: u8) -> Vec<(Note, u8)> {
+        const MAX_VELOCITY: u8 = 127;
+        self.note_iter()
+            .enumerate()
+            .map(|(i, note)| {
+                let velocity = if i == 0 { base.saturating_add(accent) } else { base };
+                (note, velocity.min(MAX_VELOCITY))
+            })
+            .collect()
+    }
+
+    /// Arpeggiate the chord in a pattern over a number of octaves, treating 0 as 1.
+    pub fn arpeggiate(&self, pattern: ArpPattern, octaves: u8) -> Vec<Note> {
+        let notes = self.notes();
+        let mut arpeggio: Vec<Note> = (0..octaves.max(1))
+            .flat_map(|octave| {
+                notes
+                    .iter()
+                    .map(move |note| Note::new(note.pitch, note.octave.saturating_add(octave)))
+            })
+            .collect();
+
+        match pattern {
+            ArpPattern::Up => {}
+            ArpPattern::Down => arpeggio.reverse(),
+            ArpPattern::UpDown => {
+                let inner = 1..arpeggio.len().saturating_sub(1);
+                let down: Vec<Note> = arpeggio[inner].iter().rev().cloned().collect();
+                arpeggio.extend(down);
+            }
+            ArpPattern::Random(seed) => {
+                // shuffle with a splitmix64 generator, so the order only depends on the seed
+                let mut state = seed;
+                let mut next = || {
+                    state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+                    let mut z = state;
+                    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+                    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+                    z ^ (z >> 31)
+                };
+                for i in (1..arpeggio.len()).rev() {
+                    let j = (next() % (i as u64 + 1)) as usize;
+                    arpeggio.swap(i, j);
+                }
+            }
+        }
+
+        arpeggio
+    }
+
+    /// Get the drop-2 voicing of the chord, lowering the second note from the top an octave.
+    pub fn drop2(&self) -> Vec<Note> {
+        self.drop_voice(2)
+    }
+
+    /// Get the drop-3 voicing of the chord, lowering the third note from the top an octave.
+    pub fn drop3(&self) -> Vec<Note> {
+        self.drop_voice(3)
+    }
+
+    /// Lower the note at a 1-based position from the top of the chord by an octave.
+    fn drop_voice(&self, from_top: usize) -> Vec<Note> {
+        let mut notes = self.notes();
+        if from_top == 0 || from_top > notes.len() {
+            return notes;
+        }
+
+        let index = notes.len() - from_top;
+        notes[index].octave = notes[index].octave.saturating_sub(1);
+        notes.sort();
+        notes
     }
 
     pub fn from_string(string: &str) -> Self {
         let notes: Vec<Pitch> = string.to_string()
                     .replace(",", "")
                     .split_whitespace()
-                    .into_iter()
-                    .map(|x| Pitch::from_str(x).expect(&format!("Invalid note {:?}.", x)))
+                    .map(|x| Pitch::from_str(x).unwrap_or_else(|| panic!("Invalid note {:?}.", x)))
                     .collect();
 
         let intervals: Vec<u8> = notes.iter()
@@ -64,74 +640,143 @@ impl Chord {
     }
 
     pub fn from_interval(root: Pitch, interval: &[u8]) -> Self {
-        use Number::*;
-        use Quality::*;
-        let (quality, number) = match interval {
-            &[4, 3] => (Major, Triad),
-            &[3, 4] => (Minor, Triad),
-            &[2, 5] => (Suspended2, Triad),
-            &[5, 2] => (Suspended4, Triad),
-            &[4, 4] => (Augmented, Triad),
-            &[3, 3] => (Diminished, Triad),
-            &[4, 3, 4] => (Major, Seventh),
-            &[3, 4, 3] => (Minor, Seventh),
-            &[4, 4, 2] => (Augmented, Seventh),
-            &[4, 4, 3] => (Augmented, MajorSeventh),
-            &[3, 3, 3] => (Diminished, Seventh),
-            &[3, 3, 4] => (HalfDiminished, Seventh),
-            &[3, 4, 4] => (Minor, MajorSeventh),
-            &[4, 3, 3] => (Dominant, Seventh),
-            &[4, 3, 3, 4] => (Dominant, Ninth),
-            &[4, 3, 4, 3] => (Major, Ninth),
-            &[4, 3, 3, 4, 4] => (Dominant, Eleventh),
-            &[4, 3, 4, 3, 3] => (Major, Eleventh),
-            &[3, 4, 3, 4, 3] => (Minor, Eleventh),
-            &[4, 3, 3, 4, 3, 4] => (Dominant, Thirteenth),
-            &[4, 3, 4, 3, 3, 4] => (Major, Thirteenth),
-            &[3, 4, 3, 4, 3, 4] => (Minor, Thirteenth),
-            _ => panic!(format!("Couldn't create chord! {:?}", interval))
-        };
-        Self::new(root, quality, number)
-    }
-
-    pub fn chord_intervals(quality: Quality, number: Number) -> Vec<Interval> {
-        use Number::*;
-        use Quality::*;
-        match (&quality, &number) {
-            (Major, Triad) => Interval::from_semitones(&[4, 3]),
-            (Minor, Triad) => Interval::from_semitones(&[3, 4]),
-            (Suspended2, Triad) => Interval::from_semitones(&[2, 5]),
-            (Suspended4, Triad) => Interval::from_semitones(&[5, 2]),
-            (Augmented, Triad) => Interval::from_semitones(&[4, 4]),
-            (Diminished, Triad) => Interval::from_semitones(&[3, 3]),
-            (Major, Seventh) => Interval::from_semitones(&[4, 3, 4]),
-            (Minor, Seventh) => Interval::from_semitones(&[3, 4, 3]),
-            (Augmented, Seventh) => Interval::from_semitones(&[4, 4, 2]),
-            (Augmented, MajorSeventh) => Interval::from_semitones(&[4, 4, 3]),
-            (Diminished, Seventh) => Interval::from_semitones(&[3, 3, 3]),
-            (HalfDiminished, Seventh) => Interval::from_semitones(&[3, 3, 4]),
-            (Minor, MajorSeventh) => Interval::from_semitones(&[3, 4, 4]),
-            (Dominant, Seventh) => Interval::from_semitones(&[4, 3, 3]),
-            (Dominant, Ninth) => Interval::from_semitones(&[4, 3, 3, 4]),
-            (Major, Ninth) => Interval::from_semitones(&[4, 3, 4, 3]),
-            (Dominant, Eleventh) => Interval::from_semitones(&[4, 3, 3, 4, 4]),
-            (Major, Eleventh) => Interval::from_semitones(&[4, 3, 4, 3, 3]),
-            (Minor, Eleventh) => Interval::from_semitones(&[3, 4, 3, 4, 3]),
-            (Dominant, Thirteenth) => Interval::from_semitones(&[4, 3, 3, 4, 3, 4]),
-            (Major, Thirteenth) => Interval::from_semitones(&[4, 3, 4, 3, 3, 4]),
-            (Minor, Thirteenth) => Interval::from_semitones(&[3, 4, 3, 4, 3, 4]),
-            _ => Interval::from_semitones(&[4, 3]),
-        }
-        .unwrap()
-    }
-
-    /// Parse a chord using a regex.
+        CHORD_PATTERNS
+            .iter()
+            .find(|(_, _, semitones)| *semitones == interval)
+            .and_then(|(quality, number, _)| Self::new(root, *quality, *number).ok())
+            .unwrap_or_else(|| panic!("Couldn't create chord! {:?}", interval))
+    }
+
+    /// Identify the chord formed by a set of notes, in any order and octave.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChordError::UnknownChord`] if the notes do not contain any known chord.
+    pub fn from_notes(notes: &[Note]) -> Result<Self, ChordError> {
+        let bass = notes
+            .iter()
+            .min_by_key(|note| note.semitones())
+            .ok_or(ChordError::UnknownChord)?;
+
+        // the distinct pitch classes above the bass, with the bass first
+        let mut pitches: Vec<Pitch> = notes.iter().map(|note| note.pitch).collect();
+        pitches.sort_by_key(|pitch| (pitch.into_u8() + 12 - bass.pitch.into_u8()) % 12);
+        pitches.dedup_by_key(|pitch| pitch.into_u8());
+
+        // the root, quality, number and tones of the largest chord found so far
+        let mut best: Option<(Pitch, Quality, Number, Vec<u8>)> = None;
+        for root in &pitches {
+            let classes: Vec<u8> = pitches
+                .iter()
+                .map(|pitch| (pitch.into_u8() + 12 - root.into_u8()) % 12)
+                .collect();
+
+            for (quality, number, semitones) in CHORD_PATTERNS.iter() {
+                let tones = Self::pattern_classes(semitones);
+                let size = best.as_ref().map_or(0, |(_, _, _, best_tones)| best_tones.len());
+                if tones.len() > size && tones.iter().all(|tone| classes.contains(tone)) {
+                    best = Some((*root, *quality, *number, tones));
+                }
+            }
+        }
+
+        let (root, quality, mut number, tones) = best.ok_or(ChordError::UnknownChord)?;
+
+        // add13 chords have the notes of sixth chords, so tell them apart by the voicing: a sixth
+        // that only sounds more than an octave above the root is an added thirteenth
+        if number == Number::Sixth {
+            let class_notes = |class: u8| notes.iter().filter(move |note| note.pitch.into_u8() == class);
+            let lowest_root = class_notes(root.into_u8()).map(Note::semitones).min();
+            let sixth = (root.into_u8() + 9) % 12;
+            if let Some(lowest_root) = lowest_root {
+                if class_notes(sixth).all(|note| note.semitones() > lowest_root + 12) {
+                    number = Number::Add13;
+                }
+            }
+        }
+
+        let bass_class = (bass.pitch.into_u8() + 12 - root.into_u8()) % 12;
+        let mut chord = match tones.iter().position(|&tone| tone == bass_class) {
+            Some(inversion) => Self::with_inversion(root, quality, number, inversion as u8)?,
+            None => Self {
+                bass: Some(bass.pitch),
+                ..Self::new(root, quality, number)?
+            },
+        };
+        chord.octave = bass.octave;
+        Ok(chord)
+    }
+
+    /// Get the pitch class of each tone of a chord pattern above its root, in root position.
+    fn pattern_classes(semitones: &[u8]) -> Vec<u8> {
+        let mut classes = vec![0];
+        for semitone in semitones {
+            classes.push((classes.last().unwrap() + semitone) % 12);
+        }
+        classes
+    }
+
+    /// Get the qualities that form a known chord with the given number.
+    pub(crate) fn qualities(number: Number) -> impl Iterator<Item = Quality> {
+        CHORD_PATTERNS
+            .iter()
+            .filter(move |(_, n, _)| *n == number)
+            .map(|(quality, _, _)| *quality)
+    }
+
+    /// Iterate over every quality and number that form a known chord.
+    pub fn all_supported() -> impl Iterator<Item = (Quality, Number)> {
+        CHORD_PATTERNS.iter().map(|(quality, number, _)| (*quality, *number))
+    }
+
+    /// Get the intervals between consecutive notes of a chord in root position.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChordError::UnknownChord`] if the quality and number don't form a known chord.
+    pub fn chord_intervals(quality: Quality, number: Number) -> Result<Vec<Interval>, ChordError> {
+        let (_, _, semitones) = CHORD_PATTERNS
+            .iter()
+            .find(|(q, n, _)| *q == quality && *n == number)
+            .ok_or(ChordError::UnknownChord)?;
+        Interval::from_semitones(semitones).map_err(|_| ChordError::UnknownChord)
+    }
+
+    /// Parse a chord like `F#m7/A`, `C Major Seventh` or `C5 major` using a regex.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChordError::UnknownQuality`] or [`ChordError::UnknownNumber`] with the text that
+    /// couldn't be parsed, and [`ChordError::UnknownChord`] if the quality and number don't form
+    /// a known chord.
+    #[cfg(feature = "std")]
     pub fn from_regex(string: &str) -> Result<Self, ChordError> {
-        let (pitch, pitch_match) = Pitch::from_regex(&string)?;
+        use Number::*;
+        let string = string.trim();
+        let (mut pitch, pitch_match) = Pitch::from_regex(string)?;
+        let mut pitch_end = pitch_match.end();
+
+        // A trailing "s" may begin a suspended quality rather than mark a sharp, as in `Csus4`
+        if pitch_match.as_str().ends_with('s') && string[pitch_end..].to_lowercase().starts_with("us") {
+            pitch_end -= 1;
+            pitch = Pitch::from_regex(&string[..pitch_end])?.0;
+        }
+
+        // A digit is an octave when a space separates it from a quality, so `C6 / A` is a
+        // sixth chord over A rather than a C triad in octave 6
+        let mut after_pitch = string[pitch_end..].chars();
+        let after_digit = string.get(pitch_end + 1..).unwrap_or_default().trim_start();
+        let (octave, quality_start) =
+            match (after_pitch.next().and_then(|c| c.to_digit(10)), after_pitch.next()) {
+                (Some(octave), Some(c)) if c.is_whitespace() && !after_digit.starts_with('/') => {
+                    (octave as u8, pitch_end + 1)
+                }
+                _ => (4, pitch_end),
+            };
 
         let slash_option = string.find('/');
         let bass_note_result = if let Some(slash) = slash_option {
-            Pitch::from_regex(&string[slash + 1..].trim())
+            Pitch::from_regex(string[slash + 1..].trim())
         } else {
             Err(NoteError::InvalidPitch)
         };
@@ -141,70 +786,174 @@ impl Chord {
             None
         };
 
-        let (quality, quality_match_option) = Quality::from_regex(
-            &string[pitch_match.end()..slash_option.unwrap_or_else(|| string.len())].trim(),
-        )?;
+        let quality_string = string[quality_start..slash_option.unwrap_or(string.len())].trim();
+        let (quality, quality_match_option) = Quality::from_regex(quality_string)?;
 
-        let number = if let Some(quality_match) = quality_match_option {
-            Number::from_regex(&string[quality_match.end()..])
-                .unwrap_or((Triad, None))
-                .0
+        let number_string = match quality_match_option {
+            Some(quality_match) => &quality_string[quality_match.end()..],
+            None => quality_string,
+        }
+        .trim();
+
+        // Report the text that couldn't be parsed, blaming the quality when nothing after the
+        // root was understood
+        let unparsed = |text: &str| match quality_match_option {
+            Some(_) => ChordError::UnknownNumber(text.to_string()),
+            None => ChordError::UnknownQuality(text.to_string()),
+        };
+        let (number, number_end) = if number_string.is_empty() {
+            (Triad, 0)
         } else {
-            Triad
+            match Number::from_regex(number_string) {
+                Ok((number, Some(number_match))) => (number, number_match.end()),
+                _ => return Err(unparsed(number_string)),
+            }
+        };
+        let remainder = number_string[number_end..].trim();
+        if !remainder.is_empty() {
+            return Err(ChordError::UnknownNumber(remainder.to_string()));
+        }
+
+        // A bare number like `C7` is a dominant chord
+        let quality = match (quality_match_option, number) {
+            (None, Seventh) | (None, Ninth) | (None, Eleventh) | (None, Thirteenth) => {
+                Quality::Dominant
+            }
+            _ => quality,
+        };
+
+        // A half-diminished chord is always a seventh chord, so `Cø` is `Cø7`
+        let number = match (quality, number_string.is_empty()) {
+            (Quality::HalfDiminished, true) => Seventh,
+            _ => number,
         };
 
-        let chord = Chord::with_inversion(
+        let mut chord = Chord::try_with_inversion(
             pitch,
             quality,
             number,
             inversion_num_option.unwrap_or(0),
-        );
+        )?;
+        chord.octave = octave;
 
         if let Ok((bass_note, _)) = bass_note_result {
-            let inversion = chord
-                .notes()
-                .iter()
-                .position(|note| note.pitch == bass_note)
-                .unwrap_or(0);
-
-            if inversion != 0 {
-                return Ok(Chord::with_inversion(
-                    pitch,
-                    quality,
-                    number,
-                    inversion as u8,
-                ));
+            // A bass note outside the chord is kept separately, as in `F/G`
+            if chord.invert_to_bass(bass_note).is_err() {
+                chord.bass = Some(bass_note);
             }
         }
 
         Ok(chord)
     }
+
+    /// Get whether the chord is built entirely from stacked thirds.
+    fn is_tertian(&self) -> bool {
+        use Number::*;
+        use Quality::*;
+        !matches!(self.quality, Suspended2 | Suspended4)
+            && matches!(self.number, Triad | Seventh | MajorSeventh | Ninth | Eleventh | Thirteenth)
+    }
+
+    /// Get the symbol of the chord's quality and number, like `m7`, if it has one.
+    fn symbol(&self) -> Option<&'static str> {
+        use Number::*;
+        use Quality::*;
+        let symbol = match (self.quality, self.number) {
+            (Major, Triad) => "",
+            (Minor, Triad) => "m",
+            (Suspended2, Triad) => "sus2",
+            (Suspended4, Triad) => "sus4",
+            (Augmented, Triad) => "aug",
+            (Diminished, Triad) => "dim",
+            (Major, Fifth) => "5",
+            (Major, Sixth) => "6",
+            (Minor, Sixth) => "m6",
+            (Major, Seventh) => "maj7",
+            (Minor, Seventh) => "m7",
+            (Augmented, Seventh) => "aug7",
+            (Augmented, MajorSeventh) => "augmaj7",
+            (Diminished, Seventh) => "dim7",
+            (HalfDiminished, Seventh) => "ø7",
+            (Minor, MajorSeventh) => "mmaj7",
+            (Dominant, Seventh) => "7",
+            (Dominant, Ninth) => "9",
+            (Major, Ninth) => "maj9",
+            (Dominant, Eleventh) => "11",
+            (Major, Eleventh) => "maj11",
+            (Minor, Eleventh) => "m11",
+            (Dominant, Thirteenth) => "13",
+            (Major, Thirteenth) => "maj13",
+            (Minor, Thirteenth) => "m13",
+            (Major, Add9) => "add9",
+            (Minor, Add9) => "madd9",
+            (Major, Add11) => "add11",
+            (Minor, Add11) => "madd11",
+            (Major, Add13) => "add13",
+            (Minor, Add13) => "madd13",
+            _ => return None,
+        };
+
+        Some(symbol)
+    }
 }
 
 impl Notes for Chord {
     fn notes(&self) -> Vec<Note> {
-        let root_note = Note {
-            pitch: self.root,
-            octave: self.octave,
+        self.note_iter().collect()
+    }
+}
+
+/// Iterates over the notes of the chord in the same order as `notes`, lowest first.
+impl IntoIterator for Chord {
+    type Item = Note;
+    type IntoIter = vec::IntoIter<Note>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.notes().into_iter()
+    }
+}
+
+/// Iterates over the notes of the chord in the same order as `notes`, lowest first.
+impl IntoIterator for &Chord {
+    type Item = Note;
+    type IntoIter = vec::IntoIter<Note>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.notes().into_iter()
+    }
+}
+
+/// Chords are equal when they sound the same set of pitch classes, in any octave or spelling.
+impl PartialEq for Chord {
+    fn eq(&self, other: &Self) -> bool {
+        let pitch_classes = |chord: &Chord| {
+            let mut classes: Vec<u8> = chord.note_iter().map(|note| note.pitch.into_u8()).collect();
+            classes.sort_unstable();
+            classes.dedup();
+            classes
         };
-        let mut notes = Interval::to_notes(root_note, self.intervals.clone());
-        notes.rotate_left(self.inversion as usize);
 
-        // Normalize to the correct octave
-        if notes[0].octave > self.octave {
-            let diff = notes[0].octave - self.octave;
-            notes.iter_mut().for_each(|note| note.octave -= diff);
+        pitch_classes(self) == pitch_classes(other)
+    }
+}
+
+impl fmt::Display for Chord {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.symbol() {
+            Some(symbol) => write!(f, "{}{}", self.root, symbol)?,
+            None => write!(f, "{} {} {}", self.root, self.quality, self.number)?,
         }
 
-        // Ensure that octave increments at the right notes
-        for i in 1..notes.len() {
-            if notes[i].pitch.into_u8() <= notes[i - 1].pitch.into_u8() {
-                notes[i].octave = notes[i - 1].octave + 1;
-            } else if notes[i].octave < notes[i - 1].octave {
-                notes[i].octave = notes[i - 1].octave;
-            }
+        // Use the same lowest note as `notes`, so inversions past the size of the chord wrap
+        // around to the matching bass
+        let lowest = self.bass_note().pitch;
+        if let Some(bass) = self.bass {
+            write!(f, "/{}", bass)?;
+        } else if lowest != self.root {
+            write!(f, "/{}", lowest)?;
         }
-        notes
+
+        Ok(())
     }
 }
 
@@ -217,6 +966,27 @@ impl Default for Chord {
             quality: Quality::Major,
             number: Number::Triad,
             inversion: 0,
+            bass: None,
         }
     }
 }
+
+/// Parses chords like `"Cmaj7"` with `Chord::from_regex`.
+#[cfg(feature = "std")]
+impl FromStr for Chord {
+    type Err = ChordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_regex(s)
+    }
+}
+
+/// Parses the same strings as `FromStr`, with `Chord::from_regex`.
+#[cfg(feature = "std")]
+impl TryFrom<&str> for Chord {
+    type Error = ChordError;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        Self::from_regex(string)
+    }
+}
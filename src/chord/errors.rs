@@ -1,33 +1,57 @@
-use crate::note::NoteError;
+use crate::note::{NoteError, Pitch};
+use alloc::string::String;
+use core::fmt;
+#[cfg(feature = "std")]
 use std::error;
-use std::fmt;
 
 /// An error while parsing a chord.
 #[derive(Debug, Clone)]
 pub enum ChordError {
     InvalidRegex,
+    /// The inversion is larger than the number of notes in the chord allows.
+    InvalidInversion { requested: u8, max: u8 },
+    /// The notes do not form any known chord.
+    UnknownChord,
+    /// The text after the root is not a known chord quality.
+    UnknownQuality(String),
+    /// The text after the quality is not a known chord number.
+    UnknownNumber(String),
+    /// The root is not a valid pitch class.
+    InvalidPitch,
+    /// The bass note is not one of the notes of the chord.
+    BassNotInChord(Pitch),
 }
 
 impl fmt::Display for ChordError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Invalid Regex!")
+        match self {
+            ChordError::InvalidRegex => write!(f, "Invalid Regex!"),
+            ChordError::InvalidInversion { requested, max } => write!(
+                f,
+                "Invalid inversion {}, the chord allows at most {}!",
+                requested, max
+            ),
+            ChordError::UnknownChord => write!(f, "Unknown chord!"),
+            ChordError::UnknownQuality(text) => write!(f, "Unknown chord quality {:?}!", text),
+            ChordError::UnknownNumber(text) => write!(f, "Unknown chord number {:?}!", text),
+            ChordError::InvalidPitch => write!(f, "Invalid pitch class!"),
+            ChordError::BassNotInChord(bass) => write!(f, "{} is not a note of the chord!", bass),
+        }
     }
 }
 
+#[cfg(feature = "std")]
 impl error::Error for ChordError {}
 
 impl From<NoteError> for ChordError {
-    fn from(e: NoteError) -> Self {
-        match e {
-            _ => ChordError::InvalidRegex,
-        }
+    fn from(_: NoteError) -> Self {
+        ChordError::InvalidPitch
     }
 }
 
+#[cfg(feature = "std")]
 impl From<regex::Error> for ChordError {
-    fn from(e: regex::Error) -> Self {
-        match e {
-            _ => ChordError::InvalidRegex,
-        }
+    fn from(_: regex::Error) -> Self {
+        ChordError::InvalidRegex
     }
 }
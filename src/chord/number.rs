@@ -1,36 +1,58 @@
+#[cfg(feature = "std")]
 use crate::chord::errors::ChordError;
+#[cfg(feature = "std")]
 use lazy_static::lazy_static;
+#[cfg(feature = "std")]
 use regex::{Match, Regex};
+#[cfg(feature = "std")]
+use core::convert::TryFrom;
 use strum_macros::Display;
 
+#[cfg(feature = "std")]
 lazy_static! {
     static ref NUMBER_REGEXES: Vec<(Regex, Number)> = {
         use Number::*;
         vec![
-            (Regex::new("(?i)(triad)").unwrap(), Triad),
-            (Regex::new("(?i)(seventh)").unwrap(), Seventh),
-            (Regex::new(r"(?i)(major\s*seventh)").unwrap(), MajorSeventh),
-            (Regex::new("(?i)(ninth)").unwrap(), Ninth),
-            (Regex::new("(?i)(eleventh)").unwrap(), Eleventh),
-            (Regex::new("(?i)(thirteenth)").unwrap(), Thirteenth),
+            (Regex::new("(?i)^(triad)").unwrap(), Triad),
+            (Regex::new("(?i)^(fifth|5)").unwrap(), Fifth),
+            (Regex::new("(?i)^(sixth|6)").unwrap(), Sixth),
+            (Regex::new(r"(?i)^(major\s*seventh|maj7)").unwrap(), MajorSeventh),
+            (Regex::new("(?i)^(seventh|7)").unwrap(), Seventh),
+            (Regex::new("(?i)^(ninth|9)").unwrap(), Ninth),
+            (Regex::new("(?i)^(eleventh|11)").unwrap(), Eleventh),
+            (Regex::new("(?i)^(thirteenth|13)").unwrap(), Thirteenth),
+            (Regex::new(r"(?i)^(add\s*9|added\s*ninth)").unwrap(), Add9),
+            (Regex::new(r"(?i)^(add\s*11|added\s*eleventh)").unwrap(), Add11),
+            (Regex::new(r"(?i)^(add\s*13|added\s*thirteenth)").unwrap(), Add13),
         ]
     };
 }
 
 /// The superscript number after a chord.
 #[derive(Display, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Number {
     Triad,
+    /// A power chord of just the root and fifth.
+    Fifth,
+    Sixth,
     Seventh,
     MajorSeventh,
     Ninth,
     Eleventh,
     Thirteenth,
+    /// A triad with an added ninth and no seventh.
+    Add9,
+    /// A triad with an added eleventh and no seventh.
+    Add11,
+    /// A triad with an added thirteenth and no seventh.
+    Add13,
 }
 
+#[cfg(feature = "std")]
 impl Number {
     /// Parse the number using a regex.
-    pub fn from_regex(string: &str) -> Result<(Self, Option<Match>), ChordError> {
+    pub fn from_regex(string: &str) -> Result<(Self, Option<Match<'_>>), ChordError> {
         for (regex, number_enum) in &*NUMBER_REGEXES {
             let mode = regex.find(string);
 
@@ -42,3 +64,17 @@ impl Number {
         Err(ChordError::InvalidRegex)
     }
 }
+
+/// Parses a whole string as a number with `Number::from_regex`.
+#[cfg(feature = "std")]
+impl TryFrom<&str> for Number {
+    type Error = ChordError;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        let string = string.trim();
+        match Number::from_regex(string)? {
+            (number, Some(number_match)) if number_match.end() == string.len() => Ok(number),
+            _ => Err(ChordError::InvalidRegex),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::chord::Chord;
+#[cfg(feature = "std")]
+use crate::chord::ChordError;
+use crate::note::{Note, Notes};
+use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::Formatter;
+#[cfg(feature = "std")]
+use core::str::FromStr;
+
+/// A sequence of chords, played one after another.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Progression {
+    /// The chords of the progression, in the order they are played.
+    pub chords: Vec<Chord>,
+}
+
+impl Progression {
+    /// Create a progression from its chords.
+    pub fn new(chords: Vec<Chord>) -> Self {
+        Progression { chords }
+    }
+
+    /// Transpose every chord of the progression by a number of semitones.
+    pub fn transpose(&self, semitones: i8) -> Progression {
+        Progression::new(self.chords.iter().map(|chord| chord.transpose(semitones)).collect())
+    }
+
+    /// Get the notes of each chord, in the same order as the chords.
+    pub fn notes_per_chord(&self) -> Vec<Vec<Note>> {
+        self.chords.iter().map(Chord::notes).collect()
+    }
+}
+
+/// Parses chord symbols separated by whitespace, like `"C Am F G"`.
+#[cfg(feature = "std")]
+impl FromStr for Progression {
+    type Err = ChordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chords = s
+            .split_whitespace()
+            .map(Chord::from_regex)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Progression::new(chords))
+    }
+}
+
+/// Formats the chord symbols separated by spaces, like `"C Am F G"`.
+impl fmt::Display for Progression {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (i, chord) in self.chords.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", chord)?;
+        }
+        Ok(())
+    }
+}
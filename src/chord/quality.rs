@@ -1,42 +1,40 @@
+#[cfg(feature = "std")]
 use crate::chord::errors::ChordError;
+#[cfg(feature = "std")]
 use lazy_static::lazy_static;
+#[cfg(feature = "std")]
 use regex::{Match, Regex};
+#[cfg(feature = "std")]
+use core::convert::TryFrom;
 use strum_macros::Display;
 
+#[cfg(feature = "std")]
 lazy_static! {
     static ref QUALITY_REGEXES: Vec<(Regex, Quality)> = {
         use Quality::*;
 
         vec![
+            (Regex::new(r"(?i)^(major|maj)").unwrap(), Major),
+            (Regex::new(r"(?i)^(minor|min)").unwrap(), Minor),
+            (Regex::new(r"^(m|-)").unwrap(), Minor),
+            (Regex::new(r"^M").unwrap(), Major),
+            (Regex::new(r"(?i)^(diminished|dim|°)").unwrap(), Diminished),
+            (Regex::new(r"^o").unwrap(), Diminished),
+            (Regex::new(r"(?i)^(augmented|aug|\+)").unwrap(), Augmented),
             (
-                Regex::new(r"^(M\s+|M$|(?i)maj|Maj|Major|major)").unwrap(),
-                Major,
-            ),
-            (
-                Regex::new(r"^(m\s+|m$|(?i)min|Min|Minor|minor)").unwrap(),
-                Minor,
-            ),
-            (Regex::new(r"(?i)^(diminished)").unwrap(), Diminished),
-            (Regex::new(r"(?i)^(augmented)").unwrap(), Augmented),
-            (
-                Regex::new(r"(?i)^(half\s*diminished|halfdiminished)").unwrap(),
+                Regex::new(r"(?i)^(half\s*diminished|halfdiminished|ø)").unwrap(),
                 HalfDiminished,
             ),
-            (Regex::new(r"(?i)^(dominant)").unwrap(), Dominant),
-            (
-                Regex::new(r"(?i)^(sus2\s+|sus2$|suspended2)").unwrap(),
-                Suspended2,
-            ),
-            (
-                Regex::new(r"(?i)^(sus4\s+|sus4$|suspended4)").unwrap(),
-                Suspended4,
-            ),
+            (Regex::new(r"(?i)^(dominant|dom)").unwrap(), Dominant),
+            (Regex::new(r"(?i)^(suspended2|sus2)").unwrap(), Suspended2),
+            (Regex::new(r"(?i)^(suspended4|sus4)").unwrap(), Suspended4),
         ]
     };
 }
 
 /// The quality of a chord.
 #[derive(Display, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Quality {
     Major,
     Minor,
@@ -48,9 +46,10 @@ pub enum Quality {
     Suspended4,
 }
 
+#[cfg(feature = "std")]
 impl Quality {
     /// Parse a quality from a regex.
-    pub fn from_regex(string: &str) -> Result<(Self, Option<Match>), ChordError> {
+    pub fn from_regex(string: &str) -> Result<(Self, Option<Match<'_>>), ChordError> {
         use Quality::*;
 
         for (regex, quality_enum) in &*QUALITY_REGEXES {
@@ -64,3 +63,16 @@ impl Quality {
         Ok((Major, None))
     }
 }
+
+/// Parses a whole string as a quality with `Quality::from_regex`.
+#[cfg(feature = "std")]
+impl TryFrom<&str> for Quality {
+    type Error = ChordError;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        match Quality::from_regex(string)? {
+            (quality, Some(quality_match)) if quality_match.end() == string.trim().len() => Ok(quality),
+            _ => Err(ChordError::InvalidRegex),
+        }
+    }
+}
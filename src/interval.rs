@@ -1,7 +1,8 @@
 //! Intervals between notes.
 
 mod errors;
+#[allow(clippy::module_inception)]
 mod interval;
 
 pub use errors::IntervalError;
-pub use interval::Interval;
+pub use interval::{Interval, Step};
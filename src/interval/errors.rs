@@ -1,5 +1,6 @@
+use core::fmt;
+#[cfg(feature = "std")]
 use std::error;
-use std::fmt;
 
 /// An error caused while creating an interval.
 #[derive(Debug, Clone)]
@@ -13,4 +14,5 @@ impl fmt::Display for IntervalError {
     }
 }
 
+#[cfg(feature = "std")]
 impl error::Error for IntervalError {}
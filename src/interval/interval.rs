@@ -1,9 +1,25 @@
 use crate::interval::errors::IntervalError;
-use crate::note::{Note, Pitch};
+use crate::note::Note;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
 use strum_macros::Display;
 
+/// The semitones in the major or perfect interval of each `Number`.
+const MAJOR_OR_PERFECT: [i32; 8] = [0, 2, 4, 5, 7, 9, 11, 12];
+
+/// The names of interval sizes, from the unison to three octaves.
+const NAMES: [&str; 22] = [
+    "Unison", "Second", "Third", "Fourth", "Fifth", "Sixth", "Seventh", "Octave", "Ninth", "Tenth",
+    "Eleventh", "Twelfth", "Thirteenth", "Fourteenth", "Fifteenth", "Sixteenth", "Seventeenth",
+    "Eighteenth", "Nineteenth", "Twentieth", "Twenty-First", "Twenty-Second",
+];
+
 /// The quality of an interval; major, minor, etc.
-#[derive(Display, Debug, Copy, Clone)]
+#[derive(Display, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Quality {
     /// A perfect interval; unisons, fourths, fifths, and octaves.
     Perfect,
@@ -14,7 +30,8 @@ pub enum Quality {
 }
 
 /// The number of an interval.
-#[derive(Display, Debug, Copy, Clone)]
+#[derive(Display, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Number {
     Unison,
     Second,
@@ -27,7 +44,8 @@ pub enum Number {
 }
 
 /// A step between notes.
-#[derive(Display, Debug, Copy, Clone)]
+#[derive(Display, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Step {
     /// A semitone step.
     Half,
@@ -35,10 +53,13 @@ pub enum Step {
     Whole,
     /// A tritone step.
     Tritone,
+    /// A step of three semitones between neighbouring letters, as in harmonic minor scales.
+    AugmentedSecond,
 }
 
 /// An interval between two notes.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Interval {
     /// The number of semitones between the notes.
     pub semitone_count: u8,
@@ -65,7 +86,7 @@ impl Interval {
     ///
     /// # Errors
     ///
-    /// Fails if `sc` is greater than 12.
+    /// Fails if no semitone counts are given.
     pub fn from_semitones(semi_tones: &[u8]) -> Result<Vec<Self>, IntervalError> {
         let mut intervals: Vec<Interval> = vec![];
 
@@ -82,10 +103,6 @@ impl Interval {
     }
 
     /// Create an interval based on the number of semitones from the root.
-    ///
-    /// # Errors
-    ///
-    /// Fails if `sc` is greater than 12.
     pub fn from_semitone(sc: u8) -> Result<Self, IntervalError> {
         let (number, quality, mut step): (Number, Quality, Option<Step>);
         step = None;
@@ -146,8 +163,10 @@ impl Interval {
                 number = Number::Octave;
                 quality = Quality::Perfect;
             }
-            _ => {
-                return Err(IntervalError::InvalidInterval);
+            13..=u8::MAX => {
+                let simple = Self::from_semitone((sc - 1) % 12 + 1)?;
+                number = simple.number;
+                quality = simple.quality;
             }
         };
 
@@ -159,6 +178,142 @@ impl Interval {
         })
     }
 
+    /// Creates the interval between two notes, taking their octaves into account.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the notes are further apart than `u8::MAX` semitones.
+    pub fn between(a: &Note, b: &Note) -> Result<Self, IntervalError> {
+        let distance = (b.semitones() - a.semitones()).abs();
+        if distance > u8::MAX as i32 {
+            return Err(IntervalError::InvalidInterval);
+        }
+
+        Self::from_semitone(distance as u8)
+    }
+
+    /// Creates the interval between two notes, named by how the notes are spelled.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the notes are further apart than `u8::MAX` semitones, or if the quality would be
+    /// beyond augmented or diminished, like C4 to F##4.
+    pub fn from_notes(a: &Note, b: &Note) -> Result<Self, IntervalError> {
+        const NUMBERS: [Number; 8] = [
+            Number::Unison,
+            Number::Second,
+            Number::Third,
+            Number::Fourth,
+            Number::Fifth,
+            Number::Sixth,
+            Number::Seventh,
+            Number::Octave,
+        ];
+        let position = |note: &Note| note.octave as i32 * 7 + note.pitch.letter as i32;
+        let (low, high) = if (position(b), b.semitones()) < (position(a), a.semitones()) {
+            (b, a)
+        } else {
+            (a, b)
+        };
+
+        let steps = position(high) - position(low);
+        let distance = high.semitones() - low.semitones();
+        if !(0..=u8::MAX as i32).contains(&distance) {
+            return Err(IntervalError::InvalidInterval);
+        }
+
+        let simple = if steps == 0 { 0 } else { (steps - 1) % 7 + 1 };
+        let octaves = (steps - simple) / 7;
+        let number = NUMBERS[simple as usize];
+        let difference = distance - MAJOR_OR_PERFECT[simple as usize] - octaves * 12;
+
+        let quality = match (number, difference) {
+            (Number::Unison, 0) | (Number::Fourth, 0) | (Number::Fifth, 0) | (Number::Octave, 0) => {
+                Quality::Perfect
+            }
+            (Number::Unison, -1)
+            | (Number::Fourth, -1)
+            | (Number::Fifth, -1)
+            | (Number::Octave, -1) => Quality::Diminished,
+            (_, 1) => Quality::Augmented,
+            (_, 0) => Quality::Major,
+            (_, -1) => Quality::Minor,
+            (_, -2) => Quality::Diminished,
+            _ => return Err(IntervalError::InvalidInterval),
+        };
+
+        Ok(Interval {
+            semitone_count: distance as u8,
+            quality,
+            number,
+            step: Self::step_of(distance as u8, quality),
+        })
+    }
+
+    /// Get the step of an interval with the given semitones and quality, if it is one.
+    fn step_of(semitone_count: u8, quality: Quality) -> Option<Step> {
+        match (semitone_count, quality) {
+            (1, Quality::Minor) => Some(Step::Half),
+            (2, Quality::Major) => Some(Step::Whole),
+            (3, Quality::Augmented) => Some(Step::AugmentedSecond),
+            (6, _) => Some(Step::Tritone),
+            _ => None,
+        }
+    }
+
+    /// Get whether the interval is larger than an octave, like a ninth.
+    pub fn is_compound(&self) -> bool {
+        self.size() > 8
+    }
+
+    /// Reduce a compound interval by whole octaves, so a major tenth becomes a major third.
+    pub fn simple(&self) -> Interval {
+        if !self.is_compound() {
+            return *self;
+        }
+
+        let octaves = (self.size() - 2) / 7;
+        let semitone_count = self.semitone_count - octaves as u8 * 12;
+        Interval {
+            semitone_count,
+            step: Self::step_of(semitone_count, self.quality),
+            ..*self
+        }
+    }
+
+    /// Get the common name of the interval, like "Minor Third" or "Major Ninth".
+    pub fn name(&self) -> String {
+        let size = self.size();
+        let number = NAMES
+            .get(size - 1)
+            .map_or_else(|| format!("{}th", size), |name| name.to_string());
+
+        match (self.quality, size) {
+            (Quality::Perfect, 1) | (Quality::Perfect, 8) => number,
+            _ => format!("{} {}", self.quality, number),
+        }
+    }
+
+    /// Get the abbreviated name of the interval, like "m3" or "M9".
+    pub fn short_name(&self) -> String {
+        let quality = match self.quality {
+            Quality::Perfect => "P",
+            Quality::Major => "M",
+            Quality::Minor => "m",
+            Quality::Augmented => "A",
+            Quality::Diminished => "d",
+        };
+
+        format!("{}{}", quality, self.size())
+    }
+
+    /// Get the size of the interval in scale steps, counting both notes, so a third is 3.
+    fn size(&self) -> usize {
+        let reference = MAJOR_OR_PERFECT[self.number as usize];
+        let octaves = (self.semitone_count as i32 - reference + 2).div_euclid(12).max(0) as usize;
+        self.number as usize + 1 + octaves * 7
+    }
+
     /// Creates an interval by inverting the given interval
     /// e.g. Perfect fifth (C to G) becomes a perfect fourth (G to C)
     pub fn invert(interval: &Self) -> Result<Self, IntervalError> {
@@ -172,27 +327,12 @@ impl Interval {
 
     /// Move the given note up by this interval.
     pub fn second_note_from(self, first_note: Note) -> Note {
-        let pitch = Pitch::from_interval(first_note.pitch, self);
-        let octave = first_note.octave;
-        let excess_octave = (first_note.pitch.into_u8() + self.semitone_count) / 12;
-
-        Note {
-            octave: octave + excess_octave,
-            pitch,
-        }
+        Note::from_semitones(first_note.semitones() + self.semitone_count as i32)
     }
 
     /// Move the given note down by this interval.
     pub fn second_note_down_from(self, first_note: Note) -> Note {
-        let pitch = Pitch::from_interval_down(first_note.pitch, self);
-        let octave = first_note.octave;
-        let raw_diff = first_note.pitch.into_u8() as i16 - self.semitone_count as i16;
-        let excess_octave = (raw_diff / -12) + if raw_diff < 0 { 1 } else { 0 };
-
-        Note {
-            octave: octave - excess_octave as u8,
-            pitch,
-        }
+        Note::from_semitones(first_note.semitones() - self.semitone_count as i32)
     }
 
     /// Produce the list of notes that have had each interval applied in order.
@@ -232,6 +372,17 @@ impl Interval {
     }
 }
 
+/// Intervals display as their name, or as their number of semitones when too large for one.
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.size() <= NAMES.len() {
+            write!(f, "{}", self.name())
+        } else {
+            write!(f, "{} semitones", self.semitone_count)
+        }
+    }
+}
+
 impl Default for Interval {
     fn default() -> Self {
         Interval {
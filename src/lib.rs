@@ -35,12 +35,23 @@
 //! let scale_notes = scale.notes();
 //!
 //! // Chord Example;
-//! let chord = Chord::new(Pitch::from(C), ChordQuality::Major, ChordNumber::Triad);
+//! let chord = Chord::new(Pitch::from(C), ChordQuality::Major, ChordNumber::Triad).unwrap();
 //!
 //! // returns a Vector of the Notes of the chord
 //! let chord_notes = chord.notes();
+//! ```
+//!
+//! ## `no_std`
+//!
+//! The library builds without the standard library when the default `std` feature is disabled;
+//! only `alloc` is required. The regex parsers (`from_regex` and `chord_from_numeral`), note
+//! frequencies and the `std::error::Error` implementations are only available with `std`.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 extern crate strum;
+
 pub mod chord;
 pub mod interval;
 pub mod note;
@@ -1,11 +1,22 @@
 //! Individual notes.
 
 mod errors;
+#[cfg(feature = "std")]
+mod frequency;
+#[allow(clippy::module_inception)]
 mod note;
 mod pitch;
+mod pitch_class_set;
 mod pitch_symbol;
+#[cfg(feature = "std")]
+mod tuning;
 
 pub use errors::NoteError;
+#[cfg(feature = "std")]
+pub use frequency::frequency_table;
 pub use note::{Note, Notes};
-pub use pitch::{Pitch, NoteLetter};
+pub use pitch::{AccidentalStyle, Pitch, NoteLetter};
+pub use pitch_class_set::PitchClassSet;
 pub use pitch_symbol::PitchSymbol;
+#[cfg(feature = "std")]
+pub use tuning::Tuning;
@@ -1,24 +1,30 @@
+use core::fmt;
+#[cfg(feature = "std")]
 use std::error;
-use std::fmt;
 
 /// An error caused when parsing a note.
 #[derive(Debug, Clone)]
 pub enum NoteError {
     InvalidPitch,
+    /// The MIDI note number is outside the range of notes that can be represented.
+    InvalidMidi(u8),
 }
 
 impl fmt::Display for NoteError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Invalid Pitch Class!")
+        match *self {
+            NoteError::InvalidPitch => write!(f, "Invalid Pitch Class!"),
+            NoteError::InvalidMidi(midi) => write!(f, "Invalid MIDI note number {}!", midi),
+        }
     }
 }
 
+#[cfg(feature = "std")]
 impl error::Error for NoteError {}
 
+#[cfg(feature = "std")]
 impl From<regex::Error> for NoteError {
-    fn from(e: regex::Error) -> Self {
-        match e {
-            _ => NoteError::InvalidPitch,
-        }
+    fn from(_: regex::Error) -> Self {
+        NoteError::InvalidPitch
     }
 }
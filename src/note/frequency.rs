@@ -0,0 +1,27 @@
+use lazy_static::lazy_static;
+
+/// The frequency of A4 used by the precomputed table, in Hz.
+pub(crate) const A4_FREQUENCY: f64 = 440.0;
+
+/// The MIDI number of A4.
+pub(crate) const A4_MIDI: i32 = 69;
+
+lazy_static! {
+    static ref FREQUENCY_TABLE: [f64; 128] = {
+        let mut table = [0.0; 128];
+        for (midi, frequency) in table.iter_mut().enumerate() {
+            *frequency = equal_temperament(midi as i32, A4_FREQUENCY);
+        }
+        table
+    };
+}
+
+/// The equal-temperament frequency of a MIDI note number for a given A4 reference.
+pub(crate) fn equal_temperament(midi: i32, a4_frequency: f64) -> f64 {
+    a4_frequency * 2f64.powf((midi - A4_MIDI) as f64 / 12.0)
+}
+
+/// Get the equal-temperament frequencies at A4 = 440 Hz of every MIDI note number.
+pub fn frequency_table() -> &'static [f64; 128] {
+    &FREQUENCY_TABLE
+}
@@ -1,13 +1,21 @@
-use crate::note::Pitch;
-use std::fmt;
-use std::fmt::Formatter;
+#[cfg(feature = "std")]
+use crate::note::frequency::{equal_temperament, frequency_table, A4_FREQUENCY};
+use crate::interval::Interval;
+use crate::note::{AccidentalStyle, NoteError, NoteLetter, Pitch};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::fmt::Formatter;
+use core::ops::{Add, Sub};
 
 /// A note.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Note {
     /// The pitch of the note (A, B, C#, etc).
     pub pitch: Pitch,
-    /// The octave of the note in standard notation.
+    /// The octave of the note in standard notation, from 0 up.
     pub octave: u8,
 }
 
@@ -19,11 +27,165 @@ impl Note {
             octave,
         }
     }
+
+    /// Raise the note by a semitone, keeping its letter (C becomes C#, E becomes E#).
+    pub fn sharpen(&self) -> Self {
+        self.with_accidental(self.pitch.accidental + 1)
+    }
+
+    /// Lower the note by a semitone, keeping its letter (D becomes Db, C becomes Cb).
+    pub fn flatten(&self) -> Self {
+        self.with_accidental(self.pitch.accidental - 1)
+    }
+
+    /// Remove any accidental from the note (C# becomes C).
+    pub fn natural(&self) -> Self {
+        self.with_accidental(0)
+    }
+
+    fn with_accidental(&self, accidental: i8) -> Self {
+        Note::new(Pitch::new(self.pitch.letter, accidental), self.octave)
+    }
+
+    /// Get the MIDI note number of the note, where C4 is 60, clamped to 127.
+    pub fn to_midi(&self) -> u8 {
+        (self.semitones() + 12).clamp(0, 127) as u8
+    }
+
+    /// Create a note from a MIDI note number, where C4 is 60, spelled with sharps.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NoteError::InvalidMidi`] if the number is above 127, or below 12 (C0) where the
+    /// octave would be negative. Notes are only created from 12 to 127, so MIDI numbers 0 to 11,
+    /// which `to_midi` gives for spellings below C0 like Cb0, don't convert back.
+    pub fn from_midi(midi: u8) -> Result<Self, NoteError> {
+        if !(12..=127).contains(&midi) {
+            return Err(NoteError::InvalidMidi(midi));
+        }
+
+        Ok(Note::from_semitones(midi as i32 - 12))
+    }
+
+    /// Get the frequency of the note in Hz, in equal temperament with A4 = 440 Hz.
+    #[cfg(feature = "std")]
+    pub fn frequency(&self) -> f64 {
+        let midi = self.semitones() + 12;
+        match frequency_table().get(midi as usize) {
+            Some(&frequency) if midi >= 0 => frequency,
+            _ => equal_temperament(midi, A4_FREQUENCY),
+        }
+    }
+
+    /// Get the frequency of the note in Hz with A4 = 440 Hz, the same value as `frequency`.
+    #[cfg(feature = "std")]
+    pub fn to_frequency(&self) -> f64 {
+        self.frequency()
+    }
+
+    /// Get the frequency of the note in Hz, in equal temperament with A4 tuned to `a4_hz`.
+    #[cfg(feature = "std")]
+    pub fn to_frequency_with_reference(&self, a4_hz: f64) -> f64 {
+        equal_temperament(self.semitones() + 12, a4_hz)
+    }
+
+    /// Create a note from the number of semitones above C0, spelled with sharps.
+    pub(crate) fn from_semitones(semitones: i32) -> Self {
+        let semitones = semitones.max(0);
+        Note::new(
+            Pitch::from_u8(semitones.rem_euclid(12) as u8),
+            semitones.div_euclid(12).min(u8::MAX as i32) as u8,
+        )
+    }
+
+    /// The number of semitones between C0 and this note, so B#3 and C4 are the same.
+    pub(crate) fn semitones(&self) -> i32 {
+        self.octave as i32 * 12 + self.pitch.letter.semitones() as i32 + self.pitch.accidental as i32
+    }
+
+    /// Move the note by a number of semitones, keeping flat notes flat and stopping at C0.
+    pub(crate) fn transpose(&self, semitones: i32) -> Self {
+        let note = Note::from_semitones((self.semitones() + semitones).max(0));
+        if self.pitch.accidental < 0 {
+            Note::new(note.pitch.as_flat(), note.octave)
+        } else {
+            note
+        }
+    }
+
+    /// Spell the same sounding note with the given letter, if that stays in octave 0 or above.
+    pub(crate) fn respell(&self, letter: NoteLetter) -> Self {
+        let semitones = self.semitones();
+        let mut accidental = (semitones - letter.semitones() as i32).rem_euclid(12);
+        if accidental > 6 {
+            accidental -= 12;
+        }
+        let octave = (semitones - letter.semitones() as i32 - accidental).div_euclid(12);
+        if !(0..=u8::MAX as i32).contains(&octave) {
+            return self.clone();
+        }
+
+        Note::new(Pitch::new(letter, accidental as i8), octave as u8)
+    }
+
+    /// Spell the same sounding note with a natural or a single sharp or flat.
+    pub fn with_style(&self, style: AccidentalStyle) -> Self {
+        let note = Note::from_semitones(self.semitones());
+        Note::new(note.pitch.with_style(style), note.octave)
+    }
+
+    /// Get the name of the note with its octave, spelled with sharps or flats, like `Db4`.
+    pub fn name_with_style(&self, style: AccidentalStyle) -> String {
+        self.with_style(style).to_string()
+    }
+
+    /// Move the note by a number of semitones and letters, spelled as the interval implies.
+    fn step(&self, semitones: i32, letters: i32) -> Self {
+        let note = Note::from_semitones((self.semitones() + semitones).max(0));
+        let spelled = note.respell(self.pitch.letter.offset(letters));
+        if spelled.semitones() == note.semitones() {
+            spelled
+        } else {
+            note
+        }
+    }
+}
+
+/// Move a note up by an interval, spelled by the interval's number.
+impl Add<Interval> for Note {
+    type Output = Note;
+
+    fn add(self, interval: Interval) -> Note {
+        self.step(interval.semitone_count as i32, interval.number as i32)
+    }
+}
+
+/// Move a note down by an interval, spelled by the interval's number.
+impl Sub<Interval> for Note {
+    type Output = Note;
+
+    fn sub(self, interval: Interval) -> Note {
+        self.step(-(interval.semitone_count as i32), -(interval.number as i32))
+    }
 }
 
+/// Notes display as their pitch, spelled as it is, followed by their octave, like `C#4`.
 impl fmt::Display for Note {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{}", self.pitch)
+        write!(f, "{}{}", self.pitch, self.octave)
+    }
+}
+
+/// Notes are ordered by how high they sound, then by accidental.
+impl Ord for Note {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.semitones(), self.pitch.accidental).cmp(&(other.semitones(), other.pitch.accidental))
+    }
+}
+
+impl PartialOrd for Note {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
@@ -32,6 +194,16 @@ pub trait Notes {
     /// Get the sequence of notes.
     fn notes(&self) -> Vec<Note>;
 
+    /// Get the lowest sounding note, or `None` if there are no notes.
+    fn lowest(&self) -> Option<Note> {
+        self.notes().into_iter().min_by_key(Note::semitones)
+    }
+
+    /// Get the highest sounding note, or `None` if there are no notes.
+    fn highest(&self) -> Option<Note> {
+        self.notes().into_iter().max_by_key(Note::semitones)
+    }
+
     /// Print the sequence of notes.
     ///
     /// By default this function will print out each notes' index and its pitch class. For example,
@@ -42,6 +214,7 @@ pub trait Notes {
     ///   2: E
     ///   3: G
     /// ```
+    #[cfg(feature = "std")]
     fn print_notes(&self) {
         let notes = self.notes();
 
@@ -1,18 +1,27 @@
 use crate::interval::Interval;
 use crate::note::errors::NoteError;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::{Add, Sub};
+use core::str::FromStr;
+#[cfg(feature = "std")]
 use lazy_static::lazy_static;
+#[cfg(feature = "std")]
 use regex::{Match, Regex};
-use std::fmt;
-use std::str::FromStr;
+use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
-use std::collections::HashMap;
 
+#[cfg(feature = "std")]
 lazy_static! {
-    static ref REGEX_PITCH: Regex = Regex::new("^[ABCDEFGabcdefg][b♭♯#s𝄪x]*").unwrap();
+    // sharps and flats are matched separately so that `Dbsus4` stops after the flat
+    static ref REGEX_PITCH: Regex =
+        Regex::new("^[ABCDEFGabcdefg](?:[b♭𝄫]+|[♯#s𝄪x]+)?").unwrap();
 }
 
 /// A note letter without an accidental.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, EnumIter)]
 pub enum NoteLetter {
     C,
     D,
@@ -23,7 +32,40 @@ pub enum NoteLetter {
     B,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+impl NoteLetter {
+    /// The number of semitones between C and this letter.
+    pub(crate) fn semitones(self) -> i8 {
+        use NoteLetter::*;
+        match self {
+            C => 0,
+            D => 2,
+            E => 4,
+            F => 5,
+            G => 7,
+            A => 9,
+            B => 11,
+        }
+    }
+
+    /// The letter the given number of steps away, wrapping around from B to C.
+    pub(crate) fn offset(self, steps: i32) -> Self {
+        use NoteLetter::*;
+        let letters = [C, D, E, F, G, A, B];
+        let index = letters.iter().position(|&letter| letter == self).unwrap() as i32;
+        letters[(index + steps).rem_euclid(7) as usize]
+    }
+}
+
+/// Whether to spell black keys with sharps or with flats.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AccidentalStyle {
+    /// Spell with sharps, like C#.
+    Sharp,
+    /// Spell with flats, like Db.
+    Flat,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Pitch {
     pub letter: NoteLetter,
     pub accidental: i8,
@@ -39,7 +81,7 @@ impl Pitch {
     /// looping back around once it reaches 12.
     pub fn from_u8(val: u8) -> Self {
         use NoteLetter::*;
-        return match val % 12 {
+        match val % 12 {
             0 => { Pitch::new(C, 0) }
             1 => { Pitch::new(C, 1) }
             2 => { Pitch::new(D, 0) }
@@ -53,26 +95,57 @@ impl Pitch {
             10 => { Pitch::new(A, 1) }
             11 => { Pitch::new(B, 0) }
             _ => panic!("impossible")
-        };
+        }
+    }
+
+    /// Get the pitch class of a MIDI note number, spelled with sharps.
+    pub fn from_midi(midi: u8) -> Self {
+        Self::from_u8(midi % 12)
+    }
+
+    /// Spell the same pitch class with a natural or a single sharp, like C# for Db.
+    pub fn as_sharp(self) -> Self {
+        Self::from_u8(self.into_u8())
+    }
+
+    /// Spell the same pitch class with a natural or a single flat, like Db for C#.
+    pub fn as_flat(self) -> Self {
+        let sharp = self.as_sharp();
+        if sharp.accidental == 0 {
+            sharp
+        } else {
+            Pitch::new(sharp.letter.offset(1), -1)
+        }
+    }
+
+    /// Spell the same pitch class with a natural or a single accidental in the given style.
+    pub fn with_style(self, style: AccidentalStyle) -> Self {
+        match style {
+            AccidentalStyle::Sharp => self.as_sharp(),
+            AccidentalStyle::Flat => self.as_flat(),
+        }
+    }
+
+    /// Get the names of every spelling of the pitch class with at most a double accidental.
+    pub fn enharmonic_equivalents(&self) -> Vec<String> {
+        let mut pitches: Vec<Pitch> = NoteLetter::iter()
+            .flat_map(|letter| (-2..=2).map(move |accidental| Pitch::new(letter, accidental)))
+            .filter(|pitch| pitch.into_u8() == self.into_u8())
+            .collect();
+        pitches.sort_by_key(|pitch| pitch.accidental.abs());
+
+        pitches.iter().map(Pitch::to_string).collect()
     }
 
     /// Convert the pitch into its corresponding integer, where 0 is C and 11 is B.
     pub fn into_u8(self) -> u8 {
-        use NoteLetter::*;
-        ((match self.letter {
-            C => 0,
-            D => 2,
-            E => 4,
-            F => 5,
-            G => 7,
-            A => 9,
-            B => 11,
-        } + self.accidental) % 12) as u8
+        (self.letter.semitones() + self.accidental).rem_euclid(12) as u8
     }
 
     /// Attempt to parse a pitch from a string. It should contain the name of the note in either
     /// uppercase or lowercase, followed by `#`, `s`, `S`, or `♯` for sharps and `b` or `♭` for
     /// flats.
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(string: &str) -> Option<Self> {
         use NoteLetter::*;
         let mut characters = string.chars();
@@ -89,40 +162,24 @@ impl Pitch {
             _ => return None,
         };
 
+        // sharps and flats can be repeated but not mixed
         let mut accidental = 0;
-        let sharps: HashMap<char, i8> =
-            [('#', 1),
-             ('s', 1),
-             ('S', 1),
-             ('♯', 1),
-             ('𝄪', 2),
-             ('x', 2)]
-             .iter().cloned().collect();
-        let flats: HashMap<char, i8> =
-            [('b', -1),
-             ('♭', -1)]
-             .iter().cloned().collect();
-        let mut active_map: Option<&HashMap<char, i8>> = None;
+        let mut sharp = None;
         for ch in characters {
-            if let Some(map) = active_map {
-                if !map.contains_key(&ch) {
-                    return None;
-                }
-                accidental += map.get(&ch).unwrap();
-            } else {
-                if sharps.contains_key(&ch) {
-                    active_map = Some(&sharps);
-                    accidental += sharps.get(&ch).unwrap();
-                } else if flats.contains_key(&ch) {
-                    active_map = Some(&flats);
-                    accidental += flats.get(&ch).unwrap();
-                } else {
-                    return None;
-                }
+            let value = match ch {
+                '#' | 's' | 'S' | '♯' => 1,
+                '𝄪' | 'x' => 2,
+                'b' | '♭' => -1,
+                '𝄫' => -2,
+                _ => return None,
+            };
+            if *sharp.get_or_insert(value > 0) != (value > 0) {
+                return None;
             }
+            accidental += value;
         }
 
-        return Some(Pitch { letter, accidental })
+        Some(Pitch { letter, accidental })
     }
 
     /// Create a pitch by moving up the given pitch by an interval.
@@ -142,8 +199,9 @@ impl Pitch {
     }
 
     /// Parse the pitch using a regex, with the same algorithm as described in `from_str`.
-    pub fn from_regex(string: &str) -> Result<(Self, Match), NoteError> {
-        let pitch_match = REGEX_PITCH.find(&string).ok_or(NoteError::InvalidPitch)?;
+    #[cfg(feature = "std")]
+    pub fn from_regex(string: &str) -> Result<(Self, Match<'_>), NoteError> {
+        let pitch_match = REGEX_PITCH.find(string).ok_or(NoteError::InvalidPitch)?;
 
         let pitch = Self::from_str(&string[pitch_match.start()..pitch_match.end()])
             .ok_or(NoteError::InvalidPitch)?;
@@ -166,7 +224,34 @@ impl fmt::Display for Pitch {
         };
 
         let acc = if self.accidental < 0 { "b" } else { "#" };
-        write!(fmt, "{}", letter.to_owned() + &(0..self.accidental.abs()).map(|_| acc).collect::<String>())
+        write!(fmt, "{}", letter)?;
+        for _ in 0..self.accidental.abs() {
+            write!(fmt, "{}", acc)?;
+        }
+        Ok(())
+    }
+}
+
+/// Move a pitch up by a number of semitones, wrapping around the octave.
+impl Add<i8> for Pitch {
+    type Output = Pitch;
+
+    fn add(self, semitones: i8) -> Pitch {
+        let pitch = Pitch::from_u8((self.into_u8() as i16 + semitones as i16).rem_euclid(12) as u8);
+        if self.accidental < 0 {
+            pitch.as_flat()
+        } else {
+            pitch
+        }
+    }
+}
+
+/// Move a pitch down by a number of semitones, wrapping around the octave.
+impl Sub<i8> for Pitch {
+    type Output = Pitch;
+
+    fn sub(self, semitones: i8) -> Pitch {
+        self + (-(semitones as i16)).rem_euclid(12) as i8
     }
 }
 
@@ -177,3 +262,28 @@ impl FromStr for Pitch {
         Self::from_str(s).ok_or(NoteError::InvalidPitch)
     }
 }
+
+/// Parses pitches like `"C#"` the same way as `FromStr`.
+impl TryFrom<&str> for Pitch {
+    type Error = NoteError;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        string.parse()
+    }
+}
+
+/// Pitches serialize as their name, like `"C#"`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Pitch {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Pitch {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        name.parse().map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,71 @@
+use crate::note::Pitch;
+use alloc::vec::Vec;
+
+/// A set of pitch classes from 0 (C) to 11 (B) for post-tonal analysis.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PitchClassSet {
+    classes: Vec<u8>,
+}
+
+impl PitchClassSet {
+    /// Create a set from the pitch classes of the given pitches, ignoring duplicates.
+    pub fn new(pitches: &[Pitch]) -> Self {
+        Self::from_classes(pitches.iter().map(|pitch| pitch.into_u8()))
+    }
+
+    /// Create a set from integer pitch classes, taken modulo 12.
+    pub fn from_classes(classes: impl IntoIterator<Item = u8>) -> Self {
+        let mut classes: Vec<u8> = classes.into_iter().map(|class| class % 12).collect();
+        classes.sort_unstable();
+        classes.dedup();
+        PitchClassSet { classes }
+    }
+
+    /// Get the pitch classes of the set in ascending order.
+    pub fn classes(&self) -> &[u8] {
+        &self.classes
+    }
+
+    /// Get the normal form of the set, its most compact rotation.
+    pub fn normal_form(&self) -> Vec<u8> {
+        let len = self.classes.len();
+        (0..len)
+            .map(|start| {
+                let mut rotation = self.classes.clone();
+                rotation.rotate_left(start);
+                rotation
+            })
+            .min_by_key(|rotation| {
+                let spans: Vec<u8> = rotation
+                    .iter()
+                    .rev()
+                    .map(|class| (class + 12 - rotation[0]) % 12)
+                    .collect();
+                (spans, rotation[0])
+            })
+            .unwrap_or_default()
+    }
+
+    /// Get the prime form of the set, transposed to start on 0.
+    pub fn prime_form(&self) -> Vec<u8> {
+        let inversion = Self::from_classes(self.classes.iter().map(|class| 12 - class));
+        let zeroed = |form: Vec<u8>| -> Vec<u8> {
+            let first = form.first().copied().unwrap_or(0);
+            form.iter().map(|class| (class + 12 - first) % 12).collect()
+        };
+
+        zeroed(self.normal_form()).min(zeroed(inversion.normal_form()))
+    }
+
+    /// Get the interval-class vector of the set, counting the pairs in each interval class.
+    pub fn interval_vector(&self) -> [u8; 6] {
+        let mut vector = [0; 6];
+        for (i, a) in self.classes.iter().enumerate() {
+            for b in &self.classes[i + 1..] {
+                let interval = (b + 12 - a) % 12;
+                vector[interval.min(12 - interval) as usize - 1] += 1;
+            }
+        }
+        vector
+    }
+}
@@ -0,0 +1,65 @@
+use crate::note::frequency::{equal_temperament, A4_FREQUENCY};
+use crate::note::{Note, Pitch};
+
+/// The frequency ratios of the twelve semitones above the tonic in five-limit just intonation.
+const JUST_RATIOS: [f64; 12] = [
+    1.0,
+    16.0 / 15.0,
+    9.0 / 8.0,
+    6.0 / 5.0,
+    5.0 / 4.0,
+    4.0 / 3.0,
+    45.0 / 32.0,
+    3.0 / 2.0,
+    8.0 / 5.0,
+    5.0 / 3.0,
+    9.0 / 5.0,
+    15.0 / 8.0,
+];
+
+/// The frequency ratios of the semitones above the tonic in Pythagorean tuning.
+const PYTHAGOREAN_RATIOS: [f64; 12] = [
+    1.0,
+    256.0 / 243.0,
+    9.0 / 8.0,
+    32.0 / 27.0,
+    81.0 / 64.0,
+    4.0 / 3.0,
+    729.0 / 512.0,
+    3.0 / 2.0,
+    128.0 / 81.0,
+    27.0 / 16.0,
+    16.0 / 9.0,
+    243.0 / 128.0,
+];
+
+/// A system for tuning notes to frequencies, relative to A4 = 440 Hz.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum Tuning {
+    /// Every semitone is the same size, as used by `Note::to_frequency`.
+    #[default]
+    EqualTemperament,
+    /// Five-limit just intonation, with small whole number ratios above the key's tonic.
+    JustIntonation { key: Pitch },
+    /// Pythagorean tuning, where the intervals above the key's tonic are built from pure fifths.
+    Pythagorean { key: Pitch },
+}
+
+impl Tuning {
+    /// Get the frequency of a note in Hz in this tuning.
+    pub fn frequency(&self, note: &Note) -> f64 {
+        let (key, ratios) = match self {
+            Tuning::EqualTemperament => return note.to_frequency(),
+            Tuning::JustIntonation { key } => (key, &JUST_RATIOS),
+            Tuning::Pythagorean { key } => (key, &PYTHAGOREAN_RATIOS),
+        };
+
+        let tonic = key.letter.semitones() as i32 + key.accidental as i32;
+        let distance = note.semitones() - tonic;
+        let tonic_frequency = equal_temperament(tonic + 12, A4_FREQUENCY);
+
+        tonic_frequency
+            * 2f64.powi(distance.div_euclid(12))
+            * ratios[distance.rem_euclid(12) as usize]
+    }
+}
@@ -1,10 +1,14 @@
 //! Scales.
 
 mod errors;
+mod key_signature;
 mod mode;
+#[allow(clippy::module_inception)]
 mod scale;
 mod scale_type;
 
+pub use errors::ScaleError;
+pub use key_signature::KeySignature;
 pub use mode::Mode;
-pub use scale::{Direction, Scale};
+pub use scale::{Direction, Scale, Tonality};
 pub use scale_type::ScaleType;
@@ -1,13 +1,26 @@
 use crate::interval::IntervalError;
 use crate::note::NoteError;
+use core::fmt;
+#[cfg(feature = "std")]
 use std::error;
-use std::fmt;
 
 #[derive(Debug, Clone)]
 pub enum ScaleError {
     InvalidInterval,
     ModeFromRegex,
     InvalidRegex,
+    /// The scale degree is outside the scale.
+    InvalidDegree(u8),
+    /// No chord of the requested kind can be built from the notes of the scale.
+    InvalidChord,
+    /// The Roman numeral can't be parsed.
+    InvalidNumeral,
+    /// The tonic is not a valid pitch class.
+    InvalidPitch,
+    /// The step is not `W`, `H` or `A`.
+    InvalidStep(char),
+    /// The scale has no standard pentatonic scale, as it is neither major nor natural minor.
+    NoPentatonic,
 }
 
 impl fmt::Display for ScaleError {
@@ -18,36 +31,38 @@ impl fmt::Display for ScaleError {
             }
             ScaleError::ModeFromRegex => write!(f, "Can't determine the mode!"),
             ScaleError::InvalidRegex => write!(f, "Invalid scale regex!"),
+            ScaleError::InvalidDegree(degree) => write!(f, "Invalid scale degree {}!", degree),
+            ScaleError::InvalidChord => write!(f, "Can't build the chord from the scale!"),
+            ScaleError::InvalidNumeral => write!(f, "Invalid Roman numeral!"),
+            ScaleError::InvalidPitch => write!(f, "Invalid pitch class!"),
+            ScaleError::InvalidStep(step) => write!(f, "Invalid scale step {}!", step),
+            ScaleError::NoPentatonic => write!(f, "The scale has no pentatonic scale!"),
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl error::Error for ScaleError {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         None
     }
 }
 
+#[cfg(feature = "std")]
 impl From<regex::Error> for ScaleError {
-    fn from(e: regex::Error) -> Self {
-        match e {
-            _ => ScaleError::ModeFromRegex,
-        }
+    fn from(_: regex::Error) -> Self {
+        ScaleError::ModeFromRegex
     }
 }
 
 impl From<NoteError> for ScaleError {
-    fn from(e: NoteError) -> Self {
-        match e {
-            _ => ScaleError::InvalidRegex,
-        }
+    fn from(_: NoteError) -> Self {
+        ScaleError::InvalidPitch
     }
 }
 
 impl From<IntervalError> for ScaleError {
-    fn from(e: IntervalError) -> Self {
-        match e {
-            _ => ScaleError::InvalidInterval,
-        }
+    fn from(_: IntervalError) -> Self {
+        ScaleError::InvalidInterval
     }
 }
@@ -0,0 +1,80 @@
+use crate::note::{NoteLetter, Pitch};
+use crate::scale::{Direction, Mode, Scale, ScaleType};
+use alloc::vec::Vec;
+
+/// The order sharps are added to a key signature in, and flats in reverse.
+const SHARP_ORDER: [NoteLetter; 7] = [
+    NoteLetter::F,
+    NoteLetter::C,
+    NoteLetter::G,
+    NoteLetter::D,
+    NoteLetter::A,
+    NoteLetter::E,
+    NoteLetter::B,
+];
+
+/// A key signature, the sharps or flats written at the start of each staff.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeySignature {
+    /// The number of sharps (positive) or flats (negative) in the signature.
+    pub fifths: i8,
+}
+
+impl KeySignature {
+    /// Create a key signature with a number of sharps (positive) or flats (negative).
+    pub fn new(fifths: i8) -> Self {
+        KeySignature { fifths }
+    }
+
+    /// Get the key signature of a scale, or `None` if it isn't written with one.
+    pub fn from_scale(scale: &Scale) -> Option<Self> {
+        let parent = |mode| {
+            Scale::new(ScaleType::Diatonic, scale.tonic, scale.octave, Some(mode), Direction::Ascending)
+        };
+        let fifths = match scale.scale_type {
+            ScaleType::Diatonic => scale.circle_of_fifths_position(),
+            ScaleType::HarmonicMinor | ScaleType::MelodicMinor => {
+                parent(Mode::Aeolian).ok()?.circle_of_fifths_position()
+            }
+            ScaleType::HarmonicMajor => parent(Mode::Ionian).ok()?.circle_of_fifths_position(),
+            _ => None,
+        }?;
+
+        Some(Self::new(fifths))
+    }
+
+    /// Get the number of sharps in the signature.
+    pub fn sharps(&self) -> u8 {
+        self.fifths.max(0) as u8
+    }
+
+    /// Get the number of flats in the signature.
+    pub fn flats(&self) -> u8 {
+        self.fifths.min(0).unsigned_abs()
+    }
+
+    /// Get the altered pitches of the signature in the order they are written.
+    pub fn accidentals(&self) -> Vec<Pitch> {
+        let count = self.fifths.unsigned_abs() as usize;
+        (0..count)
+            .map(|i| {
+                let alteration = (i / 7) as i8 + 1;
+                if self.fifths > 0 {
+                    Pitch::new(SHARP_ORDER[i % 7], alteration)
+                } else {
+                    Pitch::new(SHARP_ORDER[6 - i % 7], -alteration)
+                }
+            })
+            .collect()
+    }
+
+    /// Get the accidental the signature gives a letter, 0 when it has none.
+    pub fn accidental(&self, letter: NoteLetter) -> i8 {
+        self.accidentals()
+            .iter()
+            .rev()
+            .find(|pitch| pitch.letter == letter)
+            .map_or(0, |pitch| pitch.accidental)
+    }
+}
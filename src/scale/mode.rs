@@ -1,26 +1,48 @@
-use crate::scale::errors::ScaleError;
-use crate::scale::errors::ScaleError::ModeFromRegex;
+#[cfg(feature = "std")]
+use crate::scale::errors::ScaleError::{self, ModeFromRegex};
+#[cfg(feature = "std")]
 use crate::scale::mode::Mode::*;
+#[cfg(feature = "std")]
 use lazy_static::lazy_static;
+#[cfg(feature = "std")]
 use regex::{Match, Regex};
+#[cfg(feature = "std")]
+use core::convert::TryFrom;
 use strum_macros::{Display, EnumIter};
 
+#[cfg(feature = "std")]
 lazy_static! {
     static ref MODE_REGEXES: Vec<(Regex, Mode)> = vec![
+        (Regex::new(r"(?i)^(whole\s*tone)").unwrap(), WholeTone),
+        (Regex::new(r"(?i)^(chromatic)").unwrap(), Chromatic),
         (
-            Regex::new(r"^(M\s+|M$|(?i)maj|major|ionian)").unwrap(),
+            Regex::new(r"^(M\s*pentatonic|(?i)maj\s*pentatonic|major\s*pentatonic|pentatonic)").unwrap(),
+            MajorPentatonic
+        ),
+        (
+            Regex::new(r"^(m\s*pentatonic|(?i)min\s*pentatonic|minor\s*pentatonic)").unwrap(),
+            MinorPentatonic
+        ),
+        (Regex::new(r"(?i)^(blues)").unwrap(), Blues),
+        (Regex::new(r"(?i)^(bebop\s*dominant|bebop)").unwrap(), BebopDominant),
+        (
+            Regex::new(r"(?i)^(har major|harmonicmajor|harmonic\s+major)").unwrap(),
+            HarmonicMajor
+        ),
+        (
+            Regex::new(r"^(M\s+|M$|(?i)major|maj|ionian)").unwrap(),
             Ionian
         ),
         (
-            Regex::new(r"(?i)(har minor|harmonicminor|harmonic\s+minor)").unwrap(),
+            Regex::new(r"(?i)^(har minor|harmonicminor|harmonic\s+minor)").unwrap(),
             HarmonicMinor
         ),
         (
-            Regex::new(r"(?i)(mel minor|melodicminor|melodic\s+minor)").unwrap(),
+            Regex::new(r"(?i)^(mel minor|melodicminor|melodic\s+minor)").unwrap(),
             MelodicMinor
         ),
         (
-            Regex::new(r"^(m\s+|m$|(?i)min|minor|aeolian)").unwrap(),
+            Regex::new(r"^(m\s+|m$|(?i)minor|min|aeolian)").unwrap(),
             Aeolian
         ),
         (Regex::new(r"(?i)^(dorian)").unwrap(), Dorian),
@@ -33,6 +55,7 @@ lazy_static! {
 
 /// The mode of a scale.
 #[derive(Display, Debug, Clone, Copy, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Mode {
     /// Also known as a major scale.
     Ionian,
@@ -45,24 +68,65 @@ pub enum Mode {
     Locrian,
     HarmonicMinor,
     MelodicMinor,
+    WholeTone,
+    Chromatic,
+    MajorPentatonic,
+    MinorPentatonic,
+    Blues,
+    /// Mixolydian with an added major seventh as a passing tone.
+    BebopDominant,
+    HarmonicMajor,
 }
 
 impl Mode {
-    /// Parse a mode using a regex.
-    pub fn from_regex(string: &str) -> Result<(Self, Match), ScaleError> {
-        for (regex, mode_enum) in &*MODE_REGEXES {
-            let mode = regex.find(string.trim());
+    /// Parse a mode from the start of a string using a regex, preferring the longest match.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScaleError::ModeFromRegex`] if no mode matches the start of the string.
+    #[cfg(feature = "std")]
+    pub fn from_regex(string: &str) -> Result<(Self, Match<'_>), ScaleError> {
+        let string = string.trim();
 
-            if let Some(mode_match) = mode {
-                return Ok((*mode_enum, mode_match));
-            };
-        }
+        // ties go to the mode listed first, since `max_by_key` keeps the last maximum
+        let longest = MODE_REGEXES
+            .iter()
+            .filter_map(|(regex, mode_enum)| Some((*mode_enum, regex.find(string)?)))
+            .filter(|(_, mode_match)| mode_match.start() == 0)
+            .rev()
+            .max_by_key(|(_, mode_match)| mode_match.end());
 
-        Err(ModeFromRegex)
+        longest.ok_or(ModeFromRegex)
     }
 
-    /// Get whether the mode is diatonic (not harmonic or melodic minor).
+    /// Get whether the mode is one of the seven diatonic modes.
     pub fn is_diatonic(self) -> bool {
-        !matches!(self, Self::HarmonicMinor | Self::MelodicMinor)
+        !matches!(
+            self,
+            Self::HarmonicMinor
+                | Self::MelodicMinor
+                | Self::WholeTone
+                | Self::Chromatic
+                | Self::MajorPentatonic
+                | Self::MinorPentatonic
+                | Self::Blues
+                | Self::BebopDominant
+                | Self::HarmonicMajor
+        )
+    }
+}
+
+/// Parses a whole string as a mode with `Mode::from_regex`.
+#[cfg(feature = "std")]
+impl TryFrom<&str> for Mode {
+    type Error = ScaleError;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        let (mode, mode_match) = Mode::from_regex(string)?;
+        if mode_match.end() == string.trim().len() {
+            Ok(mode)
+        } else {
+            Err(ModeFromRegex)
+        }
     }
 }
@@ -1,24 +1,61 @@
-use crate::interval::Interval;
+#[cfg(feature = "std")]
+use crate::chord::Quality;
+use crate::chord::{Chord, Number};
+use crate::interval::{Interval, Step};
 use crate::note::{Note, Notes, Pitch, NoteLetter};
 use crate::scale::errors::ScaleError;
 use crate::scale::{Mode, ScaleType};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
+use core::convert::TryFrom;
+#[cfg(feature = "std")]
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use lazy_static::lazy_static;
+#[cfg(feature = "std")]
+use regex::Regex;
 use strum_macros::Display;
 
+#[cfg(feature = "std")]
+lazy_static! {
+    static ref REGEX_NUMERAL: Regex = Regex::new(
+        r"^(?P<numeral>VII|VI|V|IV|III|II|I|vii|vi|v|iv|iii|ii|i)(?P<quality>°|o|ø|\+)?(?P<seventh>maj7|7)?$"
+    )
+    .unwrap();
+    static ref REGEX_ALTERATION: Regex = Regex::new(r"^(?P<accidental>[b♭#♯])(?P<degree>\d+)$").unwrap();
+}
+
 /// The direction of the scale; up or down.
 #[derive(Display, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Direction {
     Ascending,
     Descending,
 }
 
+/// Whether a scale sounds major or minor, from the third above its tonic.
+#[derive(Display, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Tonality {
+    Major,
+    Minor,
+    /// Neither major nor minor, like the whole tone and chromatic scales.
+    Modal,
+}
+
 /// A scale.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Scale {
     /// The root note of the scale.
     pub tonic: Pitch,
     /// The octave of the root note of the scale.
     pub octave: u8,
-    /// The type of scale (diatonic, melodic minor, harmonic minor).
+    /// The type of scale (diatonic, melodic minor, harmonic minor, pentatonic, etc).
     pub scale_type: ScaleType,
     /// The mode of the scale.
     pub mode: Option<Mode>,
@@ -29,7 +66,15 @@ pub struct Scale {
 }
 
 impl Scale {
+    /// The octave a parsed scale starts in when no octave is given, as in `C major`.
+    pub const DEFAULT_OCTAVE: u8 = 4;
+
     /// Create a new scale with a given direction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScaleError::InvalidInterval`] for `ScaleType::Custom`, which has no intervals of
+    /// its own; use `Scale::from_intervals` instead.
     pub fn new(
         scale_type: ScaleType,
         tonic: Pitch,
@@ -41,6 +86,14 @@ impl Scale {
             ScaleType::Diatonic => Interval::from_semitones(&[2, 2, 1, 2, 2, 2, 1]),
             ScaleType::HarmonicMinor => Interval::from_semitones(&[2, 1, 2, 2, 1, 3, 1]),
             ScaleType::MelodicMinor => Interval::from_semitones(&[2, 1, 2, 2, 2, 2, 1]),
+            ScaleType::WholeTone => Interval::from_semitones(&[2, 2, 2, 2, 2, 2]),
+            ScaleType::Chromatic => Interval::from_semitones(&[1; 12]),
+            ScaleType::MajorPentatonic => Interval::from_semitones(&[2, 2, 3, 2, 3]),
+            ScaleType::MinorPentatonic => Interval::from_semitones(&[3, 2, 2, 3, 2]),
+            ScaleType::Blues => Interval::from_semitones(&[3, 2, 1, 1, 3, 2]),
+            ScaleType::BebopDominant => Interval::from_semitones(&[2, 2, 1, 2, 2, 1, 1, 1]),
+            ScaleType::HarmonicMajor => Interval::from_semitones(&[2, 2, 1, 2, 1, 3, 1]),
+            ScaleType::Custom => return Err(ScaleError::InvalidInterval),
         }?;
 
         Ok(Scale {
@@ -53,31 +106,532 @@ impl Scale {
         })
     }
 
-    /// Parse a scale from a regex.
+    /// Create an ascending scale from the semitones between each of its notes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScaleError::InvalidInterval`] if a step is zero or the steps don't add up to
+    /// 12 semitones.
+    pub fn from_intervals(tonic: Pitch, octave: u8, semitones: &[u8]) -> Result<Self, ScaleError> {
+        if semitones.contains(&0) || semitones.iter().map(|&step| step as u32).sum::<u32>() != 12 {
+            return Err(ScaleError::InvalidInterval);
+        }
+
+        Ok(Scale {
+            tonic,
+            octave,
+            scale_type: ScaleType::Custom,
+            mode: None,
+            intervals: Interval::from_semitones(semitones)?,
+            direction: Direction::Ascending,
+        })
+    }
+
+    /// Create an ascending scale from its steps, like `W W H W W W H`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScaleError::InvalidStep`] for an unknown step, and
+    /// [`ScaleError::InvalidInterval`] if the steps don't add up to an octave.
+    pub fn from_steps(tonic: Pitch, pattern: &str) -> Result<Self, ScaleError> {
+        let semitones = pattern
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .map(|c| match c.to_ascii_uppercase() {
+                'H' => Ok(1),
+                'W' => Ok(2),
+                'A' => Ok(3),
+                _ => Err(ScaleError::InvalidStep(c)),
+            })
+            .collect::<Result<Vec<u8>, _>>()?;
+
+        Self::from_intervals(tonic, Self::DEFAULT_OCTAVE, &semitones)
+    }
+
+    /// Get the steps between consecutive notes of the scale, or `None` for scales like the
+    /// pentatonics that have steps other than W, H or A.
+    pub fn step_pattern(&self) -> Option<Vec<Step>> {
+        let intervals = self.mode_intervals();
+        let heptatonic = intervals.len() == 7;
+        intervals
+            .iter()
+            .map(|interval| match interval.semitone_count {
+                1 => Some(Step::Half),
+                2 => Some(Step::Whole),
+                3 if heptatonic => Some(Step::AugmentedSecond),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Get the intervals between consecutive notes of the scale type, before the mode rotates them.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    /// Parse a scale like `C5 major` or `C major #4` using a regex.
+    #[cfg(feature = "std")]
     pub fn from_regex_in_direction(string: &str, direction: Direction) -> Result<Self, ScaleError> {
-        let (tonic, tonic_match) = Pitch::from_regex(&string.trim())?;
-        let mode_string = &string[tonic_match.end()..].trim();
-        let (mode, _) = Mode::from_regex(mode_string)?;
+        let string = string.trim();
+        let (tonic, tonic_match) = Pitch::from_regex(string)?;
+        let after_tonic = &string[tonic_match.end()..];
+        let (octave, mode_string) = match after_tonic.chars().next().and_then(|c| c.to_digit(10)) {
+            Some(octave) => (octave as u8, after_tonic[1..].trim()),
+            None => (Self::DEFAULT_OCTAVE, after_tonic.trim()),
+        };
+        let (mode, mode_match) = Mode::from_regex(mode_string)?;
         let scale_type = ScaleType::from_mode(mode);
-        let octave = 4;
-        let scale = Scale::new(scale_type, tonic, octave, Some(mode), direction)?;
+        let mut scale = Scale::new(scale_type, tonic, octave, Some(mode), direction)?;
+
+        for alteration in mode_string[mode_match.end()..].split_whitespace() {
+            let captures = REGEX_ALTERATION.captures(alteration).ok_or(ScaleError::InvalidRegex)?;
+            let semitones = if &captures["accidental"] == "b" || &captures["accidental"] == "♭" {
+                -1
+            } else {
+                1
+            };
+            let degree = captures["degree"].parse().map_err(|_| ScaleError::InvalidRegex)?;
+            scale = scale.alter_degree(degree, semitones)?;
+        }
+
         Ok(scale)
     }
 
+    /// Raise or lower a 1-based degree of the scale by a number of semitones.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScaleError::InvalidDegree`] if the degree is the tonic or outside the scale,
+    /// and [`ScaleError::InvalidInterval`] if the altered degree would reach or pass one of
+    /// its neighbours.
+    pub fn alter_degree(&self, degree: u8, semitones: i8) -> Result<Scale, ScaleError> {
+        let mut positions: Vec<i32> = self.chromatic_positions().into_iter().map(i32::from).collect();
+        if degree < 2 || degree as usize > positions.len() {
+            return Err(ScaleError::InvalidDegree(degree));
+        }
+
+        positions[degree as usize - 1] += semitones as i32;
+        positions.push(12);
+        if positions.windows(2).any(|pair| pair[1] <= pair[0]) {
+            return Err(ScaleError::InvalidInterval);
+        }
+        let steps: Vec<u8> = positions.windows(2).map(|pair| (pair[1] - pair[0]) as u8).collect();
+
+        Ok(Scale {
+            direction: self.direction,
+            ..Scale::from_intervals(self.tonic, self.octave, &steps)?
+        })
+    }
+
+    #[cfg(feature = "std")]
     pub fn from_regex(string: &str) -> Result<Self, ScaleError> {
         Self::from_regex_in_direction(string, Direction::Ascending)
     }
-}
 
-impl Notes for Scale {
-    fn notes(&self) -> Vec<Note> {
-        use Direction::*;
+    /// Transpose the scale up or down by a number of semitones, keeping its type and mode.
+    pub fn transpose(&self, semitones: i8) -> Scale {
+        let tonic = Note::new(self.tonic, self.octave).transpose(semitones as i32);
+        Scale {
+            tonic: tonic.pitch,
+            octave: tonic.octave,
+            ..self.clone()
+        }
+    }
+
+    /// Get the relative minor of a major scale, or `None` if the scale is not major.
+    pub fn relative_minor(&self) -> Option<Scale> {
+        if !self.is_major_key() {
+            return None;
+        }
+
+        let tonic = self.degree_note(5);
+        self.with_tonic(tonic, Mode::Aeolian)
+    }
+
+    /// Get the relative major of a minor scale, or `None` if the scale is not minor.
+    pub fn relative_major(&self) -> Option<Scale> {
+        if !self.is_minor_key() {
+            return None;
+        }
+
+        let tonic = self.degree_note(2);
+        self.with_tonic(tonic, Mode::Ionian)
+    }
+
+    /// Get the number of sharps (positive) or flats (negative) in a diatonic scale's key.
+    pub fn circle_of_fifths_position(&self) -> Option<i8> {
+        if self.scale_type != ScaleType::Diatonic || !matches!(self.mode, Some(mode) if mode.is_diatonic()) {
+            return None;
+        }
+
+        Some((0..7).map(|degree| self.degree_note(degree).pitch.accidental).sum())
+    }
+
+    /// Get the notes of the scale up and back down, with melodic minor descending as natural minor.
+    pub fn notes_ascending_descending(&self) -> (Vec<Note>, Vec<Note>) {
+        let ascending = Scale {
+            direction: Direction::Ascending,
+            ..self.clone()
+        };
+
+        let mut descending = match self.scale_type {
+            ScaleType::MelodicMinor => ascending
+                .with_tonic(Note::new(self.tonic, self.octave), Mode::Aeolian)
+                .map_or_else(|| ascending.notes(), |minor| minor.notes()),
+            _ => ascending.notes(),
+        };
+        descending.reverse();
+
+        (ascending.notes(), descending)
+    }
+
+    /// Get the pentatonic scale within a major or natural minor scale.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScaleError::NoPentatonic`] for other scales.
+    pub fn to_pentatonic(&self) -> Result<Scale, ScaleError> {
+        let mode = if self.is_major_key() {
+            Mode::MajorPentatonic
+        } else if self.scale_type == ScaleType::Diatonic && self.mode == Some(Mode::Aeolian) {
+            Mode::MinorPentatonic
+        } else {
+            return Err(ScaleError::NoPentatonic);
+        };
+
+        Scale::new(ScaleType::from_mode(mode), self.tonic, self.octave, Some(mode), self.direction)
+    }
+
+    /// Get the natural minor scale on the tonic of a major scale, or `None` if it is not major.
+    pub fn parallel_minor(&self) -> Option<Scale> {
+        if !self.is_major_key() {
+            return None;
+        }
+
+        self.with_tonic(Note::new(self.tonic, self.octave), Mode::Aeolian)
+    }
+
+    /// Get the major scale on the tonic of a minor scale, or `None` if it is not minor.
+    pub fn parallel_major(&self) -> Option<Scale> {
+        if !self.is_minor_key() {
+            return None;
+        }
+
+        self.with_tonic(Note::new(self.tonic, self.octave), Mode::Ionian)
+    }
+
+    /// Get every rotation of the scale, starting on each of its degrees in turn.
+    pub fn modes(&self) -> Vec<Scale> {
         use Mode::*;
-        let root_note = Note {
-            octave: self.octave,
-            pitch: self.tonic,
+        let diatonic_modes = [Ionian, Dorian, Phrygian, Lydian, Mixolydian, Aeolian, Locrian];
+        let intervals = self.mode_intervals();
+
+        (0..intervals.len())
+            .map(|degree| {
+                let tonic = self.degree_note(degree);
+
+                if self.scale_type == ScaleType::Diatonic {
+                    let mode = self.mode.unwrap_or(Ionian);
+                    let start = diatonic_modes.iter().position(|&m| m == mode).unwrap_or(0);
+                    let mode = diatonic_modes[(start + degree) % diatonic_modes.len()];
+                    if let Ok(scale) = Scale::new(
+                        ScaleType::Diatonic,
+                        tonic.pitch,
+                        tonic.octave,
+                        Some(mode),
+                        self.direction,
+                    ) {
+                        return scale;
+                    }
+                }
+
+                let mut intervals = intervals.clone();
+                intervals.rotate_left(degree);
+                Scale {
+                    tonic: tonic.pitch,
+                    octave: tonic.octave,
+                    mode: None,
+                    intervals,
+                    ..self.clone()
+                }
+            })
+            .collect()
+    }
+
+    /// Get whether the scale is major, minor or modal, from its mode or its third.
+    pub fn tonality(&self) -> Tonality {
+        use Mode::*;
+        match self.mode {
+            Some(Ionian) | Some(Lydian) | Some(Mixolydian) | Some(HarmonicMajor)
+            | Some(MajorPentatonic) | Some(BebopDominant) => Tonality::Major,
+            Some(Aeolian) | Some(Dorian) | Some(Phrygian) | Some(Locrian) | Some(HarmonicMinor)
+            | Some(MelodicMinor) | Some(MinorPentatonic) | Some(Blues) => Tonality::Minor,
+            Some(WholeTone) | Some(Chromatic) => Tonality::Modal,
+            None => {
+                let positions = self.chromatic_positions();
+                match (positions.contains(&4), positions.contains(&3)) {
+                    (true, false) => Tonality::Major,
+                    (false, true) => Tonality::Minor,
+                    _ => Tonality::Modal,
+                }
+            }
+        }
+    }
+
+    /// Get whether the scale has a major tonality, like the major, lydian and mixolydian scales.
+    pub fn is_major(&self) -> bool {
+        self.tonality() == Tonality::Major
+    }
+
+    /// Get whether the scale has a minor tonality, like the minor and dorian scales.
+    pub fn is_minor(&self) -> bool {
+        self.tonality() == Tonality::Minor
+    }
+
+    fn is_major_key(&self) -> bool {
+        self.scale_type == ScaleType::Diatonic && matches!(self.mode, None | Some(Mode::Ionian))
+    }
+
+    fn is_minor_key(&self) -> bool {
+        match self.scale_type {
+            ScaleType::Diatonic => self.mode == Some(Mode::Aeolian),
+            ScaleType::HarmonicMinor | ScaleType::MelodicMinor => true,
+            _ => false,
+        }
+    }
+
+    fn with_tonic(&self, tonic: Note, mode: Mode) -> Option<Scale> {
+        Scale::new(ScaleType::Diatonic, tonic.pitch, tonic.octave, Some(mode), self.direction).ok()
+    }
+
+    /// Get the semitones from the tonic to each degree of the scale, like `[0, 2, 4, 5, 7, 9, 11]`.
+    pub fn chromatic_positions(&self) -> Vec<u8> {
+        let intervals = self.mode_intervals();
+        let mut positions = vec![0];
+
+        for interval in &intervals[..intervals.len().saturating_sub(1)] {
+            positions.push(positions.last().unwrap() + interval.semitone_count);
+        }
+
+        positions
+    }
+
+    /// Check whether a pitch class is in the scale, ignoring octave and spelling.
+    pub fn contains(&self, pitch: Pitch) -> bool {
+        self.degree_of(pitch).is_some()
+    }
+
+    /// Get the 1-based degree of a pitch in the scale, or `None` if it is not in the scale.
+    pub fn degree_of(&self, pitch: Pitch) -> Option<u8> {
+        let position = (pitch.into_u8() + 12 - self.tonic.into_u8()) % 12;
+        self.chromatic_positions()
+            .iter()
+            .position(|&p| p == position)
+            .map(|degree| degree as u8 + 1)
+    }
+
+    /// Get the note on a 1-based degree of the ascending scale, continuing up past the octave.
+    pub fn note_on_degree(&self, degree: u8) -> Option<Note> {
+        let step = (degree as usize).checked_sub(1)?;
+        Some(self.degree_note(step))
+    }
+
+    /// Format the ascending scale with its degrees and intervals, like `1: C  2: D (M2)`.
+    pub fn format_degrees(&self) -> String {
+        let tonic = Note::new(self.tonic, self.octave);
+        let degrees = self.chromatic_positions().len() as u8 + 1;
+
+        (1..=degrees)
+            .filter_map(|degree| self.note_on_degree(degree).map(|note| (degree, note)))
+            .map(|(degree, note)| {
+                if degree == 1 {
+                    return format!("{}: {}", degree, note.pitch);
+                }
+                let interval = Interval::from_notes(&tonic, &note)
+                    .or_else(|_| Interval::between(&tonic, &note))
+                    .map(|interval| interval.short_name())
+                    .unwrap_or_default();
+                format!("{}: {} ({})", degree, note.pitch, interval)
+            })
+            .collect::<Vec<String>>()
+            .join("  ")
+    }
+
+    /// Print the ascending scale with its degrees and intervals, as `format_degrees` formats it.
+    #[cfg(feature = "std")]
+    pub fn print_with_degrees(&self) {
+        println!("{}", self.format_degrees());
+    }
+
+    /// Check whether every pitch class is in the scale, ignoring octave and spelling.
+    pub fn contains_all(&self, pitches: &[Pitch]) -> bool {
+        pitches.iter().all(|&pitch| self.contains(pitch))
+    }
+
+    /// Get the pitches that are not in the scale, in the order they were given.
+    pub fn out_of_key(&self, pitches: &[Pitch]) -> Vec<Pitch> {
+        pitches
+            .iter()
+            .filter(|&&pitch| !self.contains(pitch))
+            .copied()
+            .collect()
+    }
+
+    /// Get the triad built on each degree of the scale from the scale's own notes.
+    pub fn diatonic_chords(&self) -> Vec<Chord> {
+        self.stacked_chords(3)
+    }
+
+    /// Get the seventh chord built on each degree of the scale from the scale's own notes.
+    pub fn diatonic_sevenths(&self) -> Vec<Chord> {
+        self.stacked_chords(4)
+    }
+
+    /// Get the seventh chord on the tonic that the scale is played over in chord-scale theory.
+    pub fn to_chord_scale(&self) -> Option<Chord> {
+        self.stacked_chord(0, 4).filter(|chord| {
+            chord.root.into_u8() == self.tonic.into_u8()
+                && matches!(chord.number, Number::Seventh | Number::MajorSeventh)
+        })
+    }
+
+    fn stacked_chords(&self, tones: usize) -> Vec<Chord> {
+        (0..self.chromatic_positions().len())
+            .filter_map(|degree| self.stacked_chord(degree, tones))
+            .collect()
+    }
+
+    /// Get the chord of a number on a 1-based degree, with the quality that fits the scale.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScaleError::InvalidDegree`] if the degree is not in the scale, or
+    /// [`ScaleError::InvalidChord`] if no chord of that number fits the scale on the degree.
+    pub fn chord_on_degree(&self, degree: u8, number: Number) -> Result<Chord, ScaleError> {
+        if degree == 0 || degree as usize > self.chromatic_positions().len() {
+            return Err(ScaleError::InvalidDegree(degree));
+        }
+
+        let root = self.degree_note(degree as usize - 1);
+        Chord::qualities(number)
+            .filter_map(|quality| {
+                let mut chord = Chord::new(root.pitch, quality, number).ok()?;
+                chord.octave = root.octave;
+                Some(chord)
+            })
+            .find(|chord| {
+                let pitches: Vec<Pitch> = chord.note_iter().map(|note| note.pitch).collect();
+                self.contains_all(&pitches)
+            })
+            .ok_or(ScaleError::InvalidChord)
+    }
+
+    /// Get the chord named by a Roman numeral like `V7` or `V/V` in the key of the scale, with
+    /// the quality given by the case and symbols of the numeral rather than by the scale.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScaleError::InvalidNumeral`] if the numeral can't be parsed, or
+    /// [`ScaleError::InvalidDegree`] if the scale has no degree for it.
+    #[cfg(feature = "std")]
+    pub fn chord_from_numeral(&self, numeral: &str) -> Result<Chord, ScaleError> {
+        use Number::*;
+        use Quality::*;
+
+        if let Some(slash) = numeral.find('/') {
+            let target = self.chord_from_numeral(&numeral[slash + 1..])?;
+            let mode = if target.quality == Minor { Mode::Aeolian } else { Mode::Ionian };
+            let key = Scale::new(ScaleType::Diatonic, target.root, target.octave, Some(mode), self.direction)?;
+            return key.chord_from_numeral(&numeral[..slash]);
+        }
+
+        let captures = REGEX_NUMERAL.captures(numeral.trim()).ok_or(ScaleError::InvalidNumeral)?;
+        let roman = &captures["numeral"];
+        let degree = ["i", "ii", "iii", "iv", "v", "vi", "vii"]
+            .iter()
+            .position(|&degree| degree == roman.to_lowercase())
+            .ok_or(ScaleError::InvalidNumeral)?;
+        let upper = roman.chars().all(char::is_uppercase);
+        let seventh = captures.name("seventh").map(|seventh| seventh.as_str());
+
+        let (quality, number) = match (captures.name("quality").map(|q| q.as_str()), seventh) {
+            (Some("°"), None) | (Some("o"), None) => (Diminished, Triad),
+            (Some("°"), Some("7")) | (Some("o"), Some("7")) => (Diminished, Seventh),
+            (Some("ø"), None) | (Some("ø"), Some("7")) => (HalfDiminished, Seventh),
+            (Some("+"), None) => (Augmented, Triad),
+            (Some("+"), Some("7")) => (Augmented, Seventh),
+            (Some("+"), Some("maj7")) => (Augmented, MajorSeventh),
+            (None, None) if upper => (Major, Triad),
+            (None, None) => (Minor, Triad),
+            (None, Some("7")) if upper => (Dominant, Seventh),
+            (None, Some("7")) => (Minor, Seventh),
+            (None, Some("maj7")) if upper => (Major, Seventh),
+            (None, Some("maj7")) => (Minor, MajorSeventh),
+            _ => return Err(ScaleError::InvalidNumeral),
+        };
+
+        if degree >= self.chromatic_positions().len() {
+            return Err(ScaleError::InvalidDegree(degree as u8 + 1));
+        }
+
+        let root = self.degree_note(degree);
+        let mut chord = Chord::new(root.pitch, quality, number).map_err(|_| ScaleError::InvalidChord)?;
+        chord.octave = root.octave;
+        Ok(chord)
+    }
+
+    /// Get the chord stacked in thirds from the scale on a degree counted from 0, if known.
+    pub(crate) fn stacked_chord(&self, degree: usize, tones: usize) -> Option<Chord> {
+        let notes: Vec<Note> = (0..tones)
+            .map(|tone| self.degree_note(degree + tone * 2))
+            .collect();
+
+        Chord::from_notes(&notes).ok()
+    }
+
+    /// Get the note on a degree of the ascending scale counted from 0, continuing upward.
+    fn degree_note(&self, step: usize) -> Note {
+        let positions = self.chromatic_positions();
+        let octaves = (step / positions.len()) as i32;
+        let note = Note::new(self.tonic, self.octave);
+        let note = Note::from_semitones(
+            note.semitones() + positions[step % positions.len()] as i32 + octaves * 12,
+        );
+
+        self.spell(&note, step)
+    }
+
+    /// Spell a note of the scale on a degree counted from 0 by the letter the degree is given.
+    fn spell(&self, note: &Note, degree: usize) -> Note {
+        let plain = || {
+            if self.tonic.accidental < 0 {
+                Note::new(note.pitch.as_flat(), note.octave)
+            } else {
+                note.clone()
+            }
         };
 
+        // seven note scales use each letter exactly once, and the other built in scales letters
+        // by their intervals, like the flat fifth of the blues scale; scales rotated into another
+        // mode have no letters of their own
+        let heptatonic = self.intervals.len() == 7;
+        let letters = match self.scale_type.degree_letters() {
+            _ if heptatonic => return note.respell(self.tonic.letter.offset(degree as i32)),
+            Some(letters) if self.mode_intervals() == self.intervals => letters,
+            _ => return plain(),
+        };
+
+        let spelled = note.respell(self.tonic.letter.offset(letters[degree % letters.len()]));
+        if spelled.pitch.accidental.abs() > 1 {
+            plain()
+        } else {
+            spelled
+        }
+    }
+
+    /// Get the intervals of the scale, shifted according to its mode.
+    fn mode_intervals(&self) -> Vec<Interval> {
+        use Mode::*;
         let mut intervals_clone = self.intervals.clone();
 
         // shift the scale based on the mode
@@ -97,10 +651,56 @@ impl Notes for Scale {
             }
         };
 
-        match &self.direction {
-            Ascending => Interval::to_notes(root_note, intervals_clone),
-            Descending => Interval::to_notes_reverse(root_note, intervals_clone),
+        intervals_clone
+    }
+}
+
+impl Notes for Scale {
+    fn notes(&self) -> Vec<Note> {
+        use Direction::*;
+        let root_note = Note {
+            octave: self.octave,
+            pitch: self.tonic,
+        };
+
+        let intervals = self.mode_intervals();
+        let len = intervals.len();
+
+        let (notes, descending) = match &self.direction {
+            Ascending => (Interval::to_notes(root_note, intervals), false),
+            Descending => (Interval::to_notes_reverse(root_note, intervals), true),
+        };
+
+        notes
+            .iter()
+            .enumerate()
+            .map(|(i, note)| {
+                let degree = if descending { (len - i % len) % len } else { i };
+                self.spell(note, degree)
+            })
+            .collect()
+    }
+}
+
+/// Scales display as their tonic and mode, like `C Major`, in a form `from_regex` reads back.
+/// Custom and altered scales display as `C Custom`, which doesn't read back.
+impl fmt::Display for Scale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match (self.mode, self.scale_type) {
+            (Some(Mode::Ionian), _) | (None, ScaleType::Diatonic) => "Major".to_string(),
+            (Some(Mode::Aeolian), _) => "Minor".to_string(),
+            (Some(mode), _) => mode.to_string(),
+            (None, scale_type) => scale_type.to_string(),
+        };
+
+        write!(f, "{}", self.tonic)?;
+        for (i, c) in name.char_indices() {
+            if i == 0 || c.is_uppercase() {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", c)?;
         }
+        Ok(())
     }
 }
 
@@ -116,3 +716,23 @@ impl Default for Scale {
         }
     }
 }
+
+/// Parses scales like `"A dorian"` with `Scale::from_regex`.
+#[cfg(feature = "std")]
+impl FromStr for Scale {
+    type Err = ScaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_regex(s)
+    }
+}
+
+/// Parses the same strings as `FromStr`, with `Scale::from_regex`.
+#[cfg(feature = "std")]
+impl TryFrom<&str> for Scale {
+    type Error = ScaleError;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        Self::from_regex(string)
+    }
+}
@@ -3,10 +3,21 @@ use strum_macros::{Display, EnumIter};
 
 /// The type of a scale.
 #[derive(Display, Debug, Clone, Copy, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ScaleType {
     Diatonic,
     MelodicMinor,
     HarmonicMinor,
+    WholeTone,
+    Chromatic,
+    MajorPentatonic,
+    MinorPentatonic,
+    Blues,
+    /// Mixolydian with an added major seventh as a passing tone.
+    BebopDominant,
+    HarmonicMajor,
+    /// A scale built from its own intervals with `Scale::from_intervals`.
+    Custom,
 }
 
 impl ScaleType {
@@ -18,11 +29,33 @@ impl ScaleType {
             Aeolian => Diatonic,
             Mode::HarmonicMinor => ScaleType::HarmonicMinor,
             Mode::MelodicMinor => ScaleType::MelodicMinor,
+            Mode::WholeTone => ScaleType::WholeTone,
+            Mode::Chromatic => ScaleType::Chromatic,
+            Mode::MajorPentatonic => ScaleType::MajorPentatonic,
+            Mode::MinorPentatonic => ScaleType::MinorPentatonic,
+            Mode::Blues => ScaleType::Blues,
+            Mode::BebopDominant => ScaleType::BebopDominant,
+            Mode::HarmonicMajor => ScaleType::HarmonicMajor,
             _ => Diatonic,
         }
     }
 }
 
+impl ScaleType {
+    /// Get how many letters above the tonic each degree is spelled, for scales without seven notes.
+    pub(crate) fn degree_letters(self) -> Option<&'static [i32]> {
+        use ScaleType::*;
+        match self {
+            WholeTone => Some(&[0, 1, 2, 3, 4, 5]),
+            MajorPentatonic => Some(&[0, 1, 2, 4, 5]),
+            MinorPentatonic => Some(&[0, 2, 3, 4, 6]),
+            Blues => Some(&[0, 2, 3, 4, 4, 6]),
+            BebopDominant => Some(&[0, 1, 2, 3, 4, 5, 6, 6]),
+            _ => None,
+        }
+    }
+}
+
 impl From<Mode> for ScaleType {
     fn from(mode: Mode) -> Self {
         Self::from_mode(mode)
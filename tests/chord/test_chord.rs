@@ -8,6 +8,10 @@ fn assert_notes(symbols: &[PitchSymbol], notes: Vec<Note>) {
     }
 }
 
+fn note(symbol: PitchSymbol, octave: u8) -> Note {
+    Note::new(Pitch::from(symbol), octave)
+}
+
 #[cfg(test)]
 mod chord_tests {
     use super::*;
@@ -16,31 +20,40 @@ mod chord_tests {
     fn test_all_chords_in_c() {
         let chord_tuples = [
             ((C, Major, Triad), vec![C, E, G]),
-            ((C, Minor, Triad), vec![C, Ds, G]),
+            ((C, Minor, Triad), vec![C, Eb, G]),
             ((C, Augmented, Triad), vec![C, E, Gs]),
-            ((C, Diminished, Triad), vec![C, Ds, Fs]),
+            ((C, Diminished, Triad), vec![C, Eb, Gb]),
             ((C, Suspended2, Triad), vec![C, D, G]),
             ((C, Suspended4, Triad), vec![C, F, G]),
+            ((C, Major, Sixth), vec![C, E, G, A]),
+            ((C, Minor, Sixth), vec![C, Eb, G, A]),
             ((C, Major, Seventh), vec![C, E, G, B]),
-            ((C, Minor, Seventh), vec![C, Ds, G, As]),
-            ((C, Augmented, Seventh), vec![C, E, Gs, As]),
+            ((C, Minor, Seventh), vec![C, Eb, G, Bb]),
+            ((C, Augmented, Seventh), vec![C, E, Gs, Bb]),
             ((C, Augmented, MajorSeventh), vec![C, E, Gs, B]),
-            ((C, Diminished, Seventh), vec![C, Ds, Fs, A]),
-            ((C, HalfDiminished, Seventh), vec![C, Ds, Fs, As]),
-            ((C, Minor, MajorSeventh), vec![C, Ds, G, B]),
-            ((C, Dominant, Seventh), vec![C, E, G, As]),
+            ((C, HalfDiminished, Seventh), vec![C, Eb, Gb, Bb]),
+            ((C, Minor, MajorSeventh), vec![C, Eb, G, B]),
+            ((C, Dominant, Seventh), vec![C, E, G, Bb]),
         ];
 
         for (chord, pitches) in chord_tuples.iter() {
             let symbols = &mut pitches.clone();
             for inversion in 0..pitches.len() {
                 assert_notes(
-                    &symbols,
-                    Chord::with_inversion(Pitch::from(chord.0), chord.1, chord.2, inversion as u8).notes(),
+                    symbols,
+                    Chord::with_inversion(Pitch::from(chord.0), chord.1, chord.2, inversion as u8).unwrap().notes(),
                 );
                 symbols.rotate_left(1);
             }
         }
+
+        // the diminished seventh is spelled with a double flat, which has no pitch symbol
+        let mut pitches = vec![Pitch::from(C), Pitch::from(Eb), Pitch::from(Gb), Pitch::new(NoteLetter::B, -2)];
+        for inversion in 0..pitches.len() {
+            let notes = Chord::with_inversion(Pitch::from(C), Diminished, Seventh, inversion as u8).unwrap().notes();
+            assert_eq!(notes.iter().map(|note| note.pitch).collect::<Vec<_>>(), pitches);
+            pitches.rotate_left(1);
+        }
     }
 
     #[test]
@@ -53,16 +66,16 @@ mod chord_tests {
             [4, 4, 5, 5, 6],
             [4, 5, 5, 6, 6],
         ];
-        for inversion in 0..octaves[0].len() {
+        for (inversion, expected) in octaves.iter().enumerate() {
             let notes =
-                Chord::with_inversion(Pitch::from(chord_desc.0), chord_desc.1, chord_desc.2, inversion as u8)
+                Chord::with_inversion(Pitch::from(chord_desc.0), chord_desc.1, chord_desc.2, inversion as u8).unwrap()
                     .notes();
             assert_eq!(
                 notes
                     .into_iter()
                     .map(|note| note.octave)
                     .collect::<Vec<u8>>(),
-                octaves[inversion]
+                expected
             );
         }
     }
@@ -72,7 +85,7 @@ mod chord_tests {
         let chord = Chord::from_regex("F major");
         assert!(chord.is_ok());
         let chord = chord.unwrap();
-        assert_notes(&vec![F, A, C], chord.notes());
+        assert_notes(&[F, A, C], chord.notes());
         assert_eq!(chord.inversion, 0);
     }
 
@@ -84,8 +97,8 @@ mod chord_tests {
         assert!(chord_num.is_ok());
         let chord = chord.unwrap();
         let chord_num = chord_num.unwrap();
-        assert_notes(&vec![C, F, A], chord.notes());
-        assert_notes(&vec![C, F, A], chord_num.notes());
+        assert_notes(&[C, F, A], chord.notes());
+        assert_notes(&[C, F, A], chord_num.notes());
         assert_eq!(chord.inversion, 2);
         assert_eq!(chord_num.inversion, 2);
     }
@@ -116,4 +129,845 @@ mod chord_tests {
             assert_eq!((root, quality, number), (chord_pair.0));
         }
     }
+
+    #[test]
+    fn test_try_with_inversion() {
+        let chord = Chord::try_with_inversion(Pitch::from(C), Major, Seventh, 3);
+        assert!(chord.is_ok());
+        assert_eq!(chord.unwrap().inversion, 3);
+
+        let chord = Chord::try_with_inversion(Pitch::from(C), Major, Seventh, 4);
+        assert!(matches!(
+            chord,
+            Err(ChordError::InvalidInversion { requested: 4, max: 3 })
+        ));
+
+        assert!(Chord::from_regex("C/3").is_err());
+    }
+
+    #[test]
+    fn test_double() {
+        let chord = Chord::new(Pitch::from(C), Major, Triad).unwrap();
+        assert_eq!(chord.voice_count(), 3);
+
+        let notes = chord.double(1, 1);
+        assert_eq!(notes.len(), 4);
+        assert_notes(&[C, E, G, C], notes.clone());
+        let octaves: Vec<u8> = notes.iter().map(|note| note.octave).collect();
+        assert_eq!(octaves, vec![4, 4, 4, 5]);
+
+        let notes = Chord::with_inversion(Pitch::from(C), Major, Triad, 1).unwrap().double(3, -1);
+        assert_notes(&[G, E, G, C], notes);
+
+        assert_eq!(chord.double(4, 1).len(), 3);
+
+        // inversions past the number of tones wrap around like they do in `notes`
+        let wrapped = Chord { inversion: 7, ..chord.clone() };
+        let first = Chord::with_inversion(Pitch::from(C), Major, Triad, 1).unwrap();
+        assert_eq!(wrapped.double(1, 1), first.double(1, 1));
+        assert_notes(&[E, G, C, C], wrapped.double(1, 1));
+        assert_eq!(Chord { octave: u8::MAX, ..chord }.double(1, 1).len(), 4);
+    }
+
+    #[test]
+    fn test_octave_regex() {
+        let chord = Chord::from_regex("C5 major").unwrap();
+        assert_eq!(chord.octave, 5);
+        assert_eq!(chord.notes()[0].octave, 5);
+
+        let chord = Chord::from_regex("F#3 minor/A").unwrap();
+        assert_eq!((chord.octave, chord.quality, chord.inversion), (3, Minor, 1));
+
+        assert_eq!(Chord::from_regex("C major").unwrap().octave, 4);
+    }
+
+    #[test]
+    fn test_note_iter() {
+        for inversion in 0..5 {
+            let chord = Chord::with_inversion(Pitch::from(Eb), Dominant, Ninth, inversion).unwrap();
+            let notes = chord.notes();
+            let iterated: Vec<Note> = chord.note_iter().collect();
+            assert_eq!(iterated.len(), notes.len());
+            for (a, b) in iterated.iter().zip(notes.iter()) {
+                assert_eq!((a.pitch, a.octave), (b.pitch, b.octave));
+            }
+        }
+
+        let chord = Chord::new(Pitch::from(C), Major, Seventh).unwrap();
+        assert_eq!(chord.note_iter().take(2).last().unwrap().pitch, Pitch::from(E));
+    }
+
+    #[test]
+    fn test_resolve() {
+        use theory::scale::{Direction, Mode, Scale, ScaleType};
+        let key = |tonic: PitchSymbol, mode: Mode| {
+            Scale::new(ScaleType::Diatonic, Pitch::from(tonic), 4, Some(mode), Direction::Ascending)
+                .unwrap()
+        };
+        let c_major = key(C, Mode::Ionian);
+        let a_minor = key(A, Mode::Aeolian);
+
+        let resolutions = [
+            (&c_major, (G, Dominant, Seventh), Some((C, Major))),
+            (&c_major, (G, Major, Triad), Some((C, Major))),
+            (&c_major, (B, Diminished, Triad), Some((C, Major))),
+            (&c_major, (B, HalfDiminished, Seventh), Some((C, Major))),
+            (&c_major, (D, Dominant, Seventh), Some((G, Major))),
+            (&c_major, (E, Dominant, Seventh), Some((A, Minor))),
+            (&c_major, (E, Major, Triad), Some((A, Minor))),
+            (&c_major, (Fs, Diminished, Seventh), Some((G, Major))),
+            (&c_major, (F, Major, Triad), None),
+            (&c_major, (D, Minor, Seventh), None),
+            (&c_major, (A, Dominant, Seventh), Some((D, Minor))),
+            (&c_major, (Fs, Dominant, Seventh), None),
+            (&a_minor, (E, Dominant, Seventh), Some((A, Minor))),
+            (&a_minor, (Gs, Diminished, Seventh), Some((A, Minor))),
+            (&a_minor, (B, HalfDiminished, Seventh), None),
+        ];
+
+        for (key, (root, quality, number), expected) in resolutions.iter() {
+            let chord = Chord::new(Pitch::from(*root), *quality, *number).unwrap();
+            let resolution = chord.resolve(key).map(|chord| (chord.root, chord.quality, chord.number));
+            let expected = expected.map(|(root, quality)| (Pitch::from(root), quality, Triad));
+            assert_eq!(resolution, expected, "{:?} {:?} {:?}", root, quality, number);
+        }
+
+        let resolution = Chord::new(Pitch::from(Bb), Dominant, Seventh).unwrap()
+            .resolve(&key(Eb, Mode::Ionian))
+            .unwrap();
+        assert_eq!(resolution.root, Pitch::from(Eb));
+    }
+
+    #[test]
+    fn test_display() {
+        let chords = [
+            (Chord::new(Pitch::from(C), Major, Triad).unwrap(), "C"),
+            (Chord::new(Pitch::from(C), Major, Seventh).unwrap(), "Cmaj7"),
+            (Chord::new(Pitch::from(G), Dominant, Seventh).unwrap(), "G7"),
+            (Chord::new(Pitch::from(Bb), Dominant, Ninth).unwrap(), "Bb9"),
+            (Chord::new(Pitch::from(B), HalfDiminished, Seventh).unwrap(), "Bø7"),
+            (Chord::new(Pitch::from(D), Suspended2, Triad).unwrap(), "Dsus2"),
+            (Chord::new(Pitch::from(Cs), Suspended4, Triad).unwrap(), "C#sus4"),
+            (Chord::new(Pitch::from(Eb), Minor, MajorSeventh).unwrap(), "Ebmmaj7"),
+            (Chord::with_inversion(Pitch::from(C), Major, Triad, 1).unwrap(), "C/E"),
+            (Chord::with_inversion(Pitch::from(Fs), Minor, Seventh, 1).unwrap(), "F#m7/A"),
+            (Chord::with_inversion(Pitch::from(C), Minor, Triad, 2).unwrap(), "Cm/G"),
+            (Chord::with_inversion(Pitch::from(Ab), Major, Triad, 1).unwrap(), "Ab/C"),
+            (Chord::with_inversion(Pitch::from(C), Minor, Triad, 1).unwrap(), "Cm/Eb"),
+            (Chord::with_inversion(Pitch::from(G), Dominant, Seventh, 3).unwrap(), "G7/F"),
+        ];
+
+        for (chord, symbol) in chords.iter() {
+            assert_eq!(chord.to_string(), *symbol);
+
+            let parsed = Chord::from_regex(symbol).unwrap();
+            assert_eq!(parsed.root, chord.root);
+            assert_eq!(parsed.quality, chord.quality);
+            assert_eq!(parsed.number, chord.number);
+            assert_eq!(parsed.inversion, chord.inversion);
+        }
+    }
+
+    #[test]
+    fn test_from_notes() {
+        // the lowest note decides the inversion, and a set that could be read as more than one
+        // chord, like the diminished seventh, is read with the lowest note as its root
+        let cases = [
+            (vec![note(C, 4), note(E, 4), note(G, 4)], (C, Major, Triad, 0)),
+            (vec![note(E, 5), note(A, 3), note(C, 4)], (A, Minor, Triad, 0)),
+            (vec![note(E, 4), note(G, 4), note(C, 5)], (C, Major, Triad, 1)),
+            (vec![note(G, 3), note(C, 4), note(E, 4), note(G, 4)], (C, Major, Triad, 2)),
+            (vec![note(G, 3), note(B, 3), note(D, 4), note(F, 4)], (G, Dominant, Seventh, 0)),
+            (vec![note(F, 3), note(G, 3), note(B, 3), note(D, 4)], (G, Dominant, Seventh, 3)),
+            (vec![note(D, 4), note(F, 4), note(Gs, 4), note(B, 4)], (D, Diminished, Seventh, 0)),
+            (vec![note(C, 4), note(D, 4), note(G, 4)], (C, Suspended2, Triad, 0)),
+        ];
+
+        for (notes, (root, quality, number, inversion)) in cases.iter() {
+            let chord = Chord::from_notes(notes).unwrap();
+            assert_eq!(chord.root, Pitch::from(*root));
+            assert_eq!(chord.quality, *quality);
+            assert_eq!(chord.number, *number);
+            assert_eq!(chord.inversion, *inversion);
+        }
+
+        let chord = Chord::from_notes(&[note(E, 4), note(G, 4), note(C, 5)]).unwrap();
+        assert_notes(&[E, G, C], chord.notes());
+        assert_eq!(chord.notes()[0].octave, 4);
+
+        assert!(Chord::from_notes(&[]).is_err());
+        assert!(Chord::from_notes(&[note(C, 4), note(Cs, 4), note(D, 4)]).is_err());
+    }
+
+    #[test]
+    fn test_transpose() {
+        let chord = Chord::new(Pitch::from(C), Major, Seventh).unwrap();
+
+        let up = chord.transpose(7);
+        assert_eq!((up.root, up.octave), (Pitch::from(G), 4));
+        assert_eq!((up.quality, up.number, up.inversion), (Major, Seventh, 0));
+        assert_notes(&[G, B, D, Fs], up.notes());
+
+        let down = chord.transpose(-3);
+        assert_eq!((down.root, down.octave), (Pitch::from(A), 3));
+        assert_notes(&[A, Cs, E, Gs], down.notes());
+        assert_eq!(down.notes()[0].octave, 3);
+        assert_eq!(down.notes()[1].octave, 4);
+
+        let octave = chord.transpose(12);
+        assert_eq!((octave.root, octave.octave), (Pitch::from(C), 5));
+        for (a, b) in octave.notes().iter().zip(chord.notes().iter()) {
+            assert_eq!((a.pitch, a.octave), (b.pitch, b.octave + 1));
+        }
+
+        let inverted = Chord::with_inversion(Pitch::from(C), Minor, Triad, 1).unwrap().transpose(2);
+        assert_eq!((inverted.root, inverted.inversion), (Pitch::from(D), 1));
+        assert_eq!(inverted.notes()[0].pitch, Pitch::from(F));
+    }
+
+    #[test]
+    fn test_sixth() {
+        let notes = Chord::from_regex("C6").unwrap().notes();
+        assert_eq!(notes.len(), 4);
+        assert_notes(&[C, E, G, A], notes);
+
+        let classes: Vec<u8> = Chord::from_regex("Cm6")
+            .unwrap()
+            .notes()
+            .iter()
+            .map(|note| note.pitch.into_u8())
+            .collect();
+        let expected: Vec<u8> = [C, Eb, G, A].iter().map(|&s| Pitch::from(s).into_u8()).collect();
+        assert_eq!(classes, expected);
+        assert_eq!(Chord::new(Pitch::from(C), Minor, Sixth).unwrap().to_string(), "Cm6");
+    }
+
+    #[test]
+    fn test_power_chord() {
+        let notes = Chord::from_regex("C5").unwrap().notes();
+        assert_eq!(notes.len(), 2);
+        assert_notes(&[C, G], notes);
+
+        let chord = Chord::from_regex("F#5").unwrap();
+        assert_notes(&[Fs, Cs], chord.notes());
+        assert_eq!(chord.to_string(), "F#5");
+        assert_eq!(chord.octave, 4);
+    }
+
+    #[test]
+    fn test_added_tones() {
+        let chord = Chord::from_regex("Cadd9").unwrap();
+        let notes = chord.notes();
+        assert_notes(&[C, E, G, D], notes.clone());
+        assert_eq!(notes[3].octave, 5);
+        assert!(!notes.iter().any(|note| note.pitch == Pitch::from(As)));
+        assert_eq!(chord.to_string(), "Cadd9");
+
+        assert_notes(&[C, E, G, F], Chord::new(Pitch::from(C), Major, Add11).unwrap().notes());
+        let add13 = Chord::new(Pitch::from(C), Major, Add13).unwrap();
+        let notes = add13.notes();
+        assert_notes(&[C, E, G, A], notes.clone());
+        assert_eq!(notes[3].octave, 5);
+        assert_eq!(add13.intervals_from_root()[3].to_string(), "Major Thirteenth");
+        assert_eq!(add13.to_string(), "Cadd13");
+
+        let minor = Chord::new(Pitch::from(C), Minor, Add13).unwrap();
+        assert_notes(&[C, Eb, G, A], minor.notes());
+        assert_eq!(minor.to_string(), "Cmadd13");
+
+        // sixth chords and add13 chords share their notes, and are told apart by the voicing
+        let number = |notes: &[Note]| Chord::from_notes(notes).unwrap().number;
+        assert_eq!(number(&[note(C, 4), note(E, 4), note(G, 4), note(A, 4)]), Sixth);
+        assert_eq!(number(&[note(C, 4), note(E, 4), note(G, 4), note(A, 5)]), Add13);
+        assert_eq!(number(&add13.notes()), Add13);
+        assert_eq!(number(&minor.notes()), Add13);
+        assert_eq!(number(&[note(C, 4), note(E, 4), note(A, 4), note(G, 5), note(A, 5)]), Sixth);
+    }
+
+    #[test]
+    fn test_eq() {
+        let first_inversion = Chord::with_inversion(Pitch::from(C), Major, Triad, 1).unwrap();
+        let built = Chord::from_notes(&[note(E, 3), note(G, 3), note(C, 4)]).unwrap();
+        assert_eq!(first_inversion, built);
+
+        assert_eq!(first_inversion, Chord::new(Pitch::from(C), Major, Triad).unwrap());
+        assert_eq!(Chord::new(Pitch::from(Db), Major, Triad).unwrap(), Chord::new(Pitch::from(Cs), Major, Triad).unwrap());
+        assert_eq!(Chord::new(Pitch::from(C), Major, Triad).unwrap().transpose(12), Chord::new(Pitch::from(C), Major, Triad).unwrap());
+        assert_eq!(Chord::new(Pitch::from(C), Major, Sixth).unwrap(), Chord::new(Pitch::from(A), Minor, Seventh).unwrap());
+
+        assert_ne!(Chord::new(Pitch::from(C), Major, Triad).unwrap(), Chord::new(Pitch::from(C), Minor, Triad).unwrap());
+        assert_ne!(Chord::new(Pitch::from(C), Major, Triad).unwrap(), Chord::new(Pitch::from(C), Major, Seventh).unwrap());
+        assert_ne!(
+            Chord::with_inversion(Pitch::from(C), Major, Triad, 2).unwrap(),
+            Chord::with_inversion(Pitch::from(G), Major, Triad, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_spelling() {
+        let pitches = |chord: Chord| -> Vec<Pitch> { chord.notes().iter().map(|note| note.pitch).collect() };
+
+        assert_eq!(
+            pitches(Chord::new(Pitch::from(C), Diminished, Seventh).unwrap()),
+            [Pitch::from(C), Pitch::from(Eb), Pitch::from(Gb), Pitch::new(NoteLetter::B, -2)]
+        );
+        assert_notes(&[Db, F, Ab], Chord::new(Pitch::from(Db), Major, Triad).unwrap().notes());
+        assert_notes(&[Fs, As, Cs, E], Chord::new(Pitch::from(Fs), Dominant, Seventh).unwrap().notes());
+        assert_notes(&[Bb, Db, F, Ab], Chord::new(Pitch::from(Bb), Minor, Seventh).unwrap().notes());
+        assert_notes(&[C, E, Gs, Bb], Chord::new(Pitch::from(C), Augmented, Seventh).unwrap().notes());
+        assert_notes(&[F, Bb, C], Chord::new(Pitch::from(F), Suspended4, Triad).unwrap().notes());
+        assert_notes(&[Gs, Bs, Ds], Chord::new(Pitch::from(Gs), Major, Triad).unwrap().notes());
+        assert_notes(&[Eb, Gb, Bb], Chord::new(Pitch::from(Eb), Minor, Triad).unwrap().notes());
+        assert_notes(&[Fs, As, Cs], Chord::new(Pitch::from(Fs), Major, Triad).unwrap().notes());
+        assert_notes(&[Cs, E, G], Chord::new(Pitch::from(Cs), Diminished, Triad).unwrap().notes());
+        assert_notes(&[Ab, C, E], Chord::new(Pitch::from(Ab), Augmented, Triad).unwrap().notes());
+        assert_eq!(
+            pitches(Chord::new(Pitch::from(Gb), Minor, Sixth).unwrap()),
+            [Pitch::from(Gb), Pitch::new(NoteLetter::B, -2), Pitch::from(Db), Pitch::from(Eb)]
+        );
+
+        assert_eq!(Pitch::from(Db).as_sharp(), Pitch::from(Cs));
+        assert_eq!(Pitch::from(Cs).as_flat(), Pitch::from(Db));
+        assert_eq!(Pitch::from(Bs).as_flat(), Pitch::from(C));
+        assert_eq!(Pitch::from(E).as_flat(), Pitch::from(E));
+        assert_eq!(Chord::new(Pitch::from(Bb), Major, Triad).unwrap().transpose(-1).root, Pitch::from(A));
+        assert_eq!(Chord::new(Pitch::from(Eb), Major, Triad).unwrap().transpose(-1).root, Pitch::from(D));
+        assert_eq!(Chord::new(Pitch::from(Ab), Major, Triad).unwrap().transpose(2).root, Pitch::from(Bb));
+    }
+
+    #[test]
+    fn test_slash_bass() {
+        let chord = Chord::from_regex("C/D").unwrap();
+        assert_eq!(chord.bass, Some(Pitch::from(D)));
+        assert_eq!(chord.inversion, 0);
+        assert_notes(&[D, C, E, G], chord.notes());
+        assert_eq!((chord.notes()[0].octave, chord.notes()[1].octave), (3, 4));
+        assert_eq!(chord.voice_count(), 4);
+        assert_eq!(chord.to_string(), "C/D");
+
+        let chord = Chord::from_regex("F/G").unwrap();
+        assert_notes(&[G, F, A, C], chord.notes());
+        assert_eq!(chord.to_string(), "F/G");
+        assert_eq!(Chord::from_regex(&chord.to_string()).unwrap().bass, chord.bass);
+
+        let inversion = Chord::from_regex("C/E").unwrap();
+        assert_eq!((inversion.bass, inversion.inversion), (None, 1));
+
+        let up = Chord::from_regex("C/D").unwrap().transpose(2);
+        assert_eq!(up.to_string(), "D/E");
+        assert_notes(&[E, D, Fs, A], up.notes());
+    }
+
+    #[test]
+    fn test_inversions_ascend() {
+        for quality in [Dominant, Major, Minor].iter() {
+            let chord = Chord::new(Pitch::from(C), *quality, Thirteenth).unwrap();
+            for inversion in 0..chord.voice_count() as u8 {
+                let inverted = Chord::with_inversion(Pitch::from(C), *quality, Thirteenth, inversion).unwrap();
+                let notes = inverted.notes();
+                assert_eq!(notes.len(), 7);
+                assert_eq!(notes[0].octave, 4);
+                for pair in notes.windows(2) {
+                    assert!(
+                        pair[0].to_midi() < pair[1].to_midi(),
+                        "{} is not below {} in inversion {}",
+                        pair[0],
+                        pair[1],
+                        inversion
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_lowest_and_highest() {
+        let chord = Chord::new(Pitch::from(C), Major, Triad).unwrap();
+        let lowest = chord.lowest().unwrap();
+        let highest = chord.highest().unwrap();
+        assert_eq!((lowest.pitch, lowest.octave), (Pitch::from(C), 4));
+        assert_eq!((highest.pitch, highest.octave), (Pitch::from(G), 4));
+
+        let inverted = Chord::with_inversion(Pitch::from(C), Major, Triad, 2).unwrap();
+        assert_eq!(inverted.lowest().unwrap().pitch, Pitch::from(G));
+        assert_eq!(inverted.highest().unwrap().pitch, Pitch::from(E));
+    }
+
+    #[test]
+    fn test_builder() {
+        let chord = ChordBuilder::new()
+            .root(Pitch::from(G))
+            .quality(Major)
+            .number(Ninth)
+            .inversion(2)
+            .octave(3)
+            .build()
+            .unwrap();
+
+        assert_eq!((chord.root, chord.quality, chord.number), (Pitch::from(G), Major, Ninth));
+        assert_eq!((chord.inversion, chord.octave), (2, 3));
+        assert_notes(&[D, Fs, A, G, B], chord.notes());
+        assert_eq!(chord.notes()[0].octave, 3);
+
+        let default = ChordBuilder::new().build().unwrap();
+        assert_eq!((default.root, default.quality, default.number), (Pitch::from(C), Major, Triad));
+
+        assert!(matches!(
+            ChordBuilder::new().inversion(3).build(),
+            Err(ChordError::InvalidInversion { requested: 3, max: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_drop_voicings() {
+        let octaves = |notes: &[Note]| notes.iter().map(|note| note.octave).collect::<Vec<u8>>();
+        let chord = Chord::new(Pitch::from(C), Major, Seventh).unwrap();
+
+        let drop2 = chord.drop2();
+        assert_notes(&[G, C, E, B], drop2.clone());
+        assert_eq!(octaves(&drop2), vec![3, 4, 4, 4]);
+
+        let drop3 = chord.drop3();
+        assert_notes(&[E, C, G, B], drop3.clone());
+        assert_eq!(octaves(&drop3), vec![3, 4, 4, 4]);
+
+        let triad = Chord::new(Pitch::from(C), Major, Triad).unwrap().drop3();
+        assert_notes(&[C, E, G], triad.clone());
+        assert_eq!(octaves(&triad), vec![3, 4, 4]);
+
+        let power_chord = Chord::new(Pitch::from(C), Major, Fifth).unwrap();
+        assert_eq!(power_chord.drop3(), power_chord.notes());
+    }
+
+    #[test]
+    fn test_double_accidentals() {
+        let chord = Chord::from_regex("G## dominant seventh").unwrap();
+        assert_eq!(chord.root, Pitch::new(NoteLetter::G, 2));
+        assert_eq!((chord.quality, chord.number), (Dominant, Seventh));
+        assert_eq!(chord.notes()[0].pitch.into_u8(), Pitch::from(A).into_u8());
+
+        let chord = Chord::from_regex("Fbbm").unwrap();
+        assert_eq!(chord.root, Pitch::new(NoteLetter::F, -2));
+        assert_eq!(chord.quality, Minor);
+    }
+
+    #[test]
+    fn test_unknown_combination() {
+        assert!(matches!(
+            Chord::new(Pitch::from(C), Suspended2, Thirteenth),
+            Err(ChordError::UnknownChord)
+        ));
+        assert!(matches!(
+            Chord::with_inversion(Pitch::from(C), Dominant, Triad, 1),
+            Err(ChordError::UnknownChord)
+        ));
+        assert!(matches!(
+            Chord::chord_intervals(Augmented, Ninth),
+            Err(ChordError::UnknownChord)
+        ));
+        assert_eq!(Chord::chord_intervals(Major, Triad).unwrap().len(), 2);
+        assert!(ChordBuilder::new().quality(Suspended4).number(Ninth).build().is_err());
+    }
+
+    #[test]
+    fn test_quality_conversions() {
+        let c = Chord::new(Pitch::from(C), Major, Triad).unwrap();
+        let minor = c.to_minor().unwrap();
+        assert_eq!((minor.root, minor.quality, minor.number), (Pitch::from(C), Minor, Triad));
+        assert_notes(&[C, Eb, G], minor.notes());
+        assert_eq!(minor.to_major().unwrap(), c);
+        assert_notes(&[C, E, Gs], c.augment().unwrap().notes());
+        assert_notes(&[C, Eb, Gb], c.diminish().unwrap().notes());
+
+        let cmaj7 = Chord::from_regex("C5 major seventh").unwrap();
+        let c7 = cmaj7.to_dominant().unwrap();
+        assert_eq!((c7.quality, c7.number, c7.octave), (Dominant, Seventh, 5));
+        assert_notes(&[C, E, G, Bb], c7.notes());
+
+        let inverted = Chord::from_regex("C/E").unwrap().to_minor().unwrap();
+        assert_eq!(inverted.inversion, 1);
+        assert_notes(&[Eb, G, C], inverted.notes());
+
+        assert!(matches!(c.to_dominant(), Err(ChordError::UnknownChord)));
+    }
+
+    #[test]
+    fn test_with_octave() {
+        let chord = Chord::with_octave(Pitch::from(C), Major, Triad, 2).unwrap();
+        assert_eq!(chord.octave, 2);
+        let notes: Vec<_> = chord.notes().iter().map(|note| (note.pitch, note.octave)).collect();
+        assert_eq!(notes, [(Pitch::from(C), 2), (Pitch::from(E), 2), (Pitch::from(G), 2)]);
+
+        let chord = Chord::with_octave(Pitch::from(A), Minor, Seventh, 3).unwrap();
+        assert_eq!(chord.notes().last().map(|note| note.octave), Some(4));
+        assert!(Chord::with_octave(Pitch::from(C), Dominant, Triad, 2).is_err());
+    }
+
+    #[test]
+    fn test_invert_to_bass() {
+        let mut chord = Chord::new(Pitch::from(C), Major, Triad).unwrap();
+        chord.invert_to_bass(Pitch::from(E)).unwrap();
+        assert_eq!(chord.inversion, 1);
+        assert_notes(&[E, G, C], chord.notes());
+
+        chord.invert_to_bass(Pitch::from(G)).unwrap();
+        assert_eq!(chord.inversion, 2);
+        chord.invert_to_bass(Pitch::from(C)).unwrap();
+        assert_eq!(chord.inversion, 0);
+
+        let mut seventh = Chord::new(Pitch::from(C), Dominant, Seventh).unwrap();
+        seventh.invert_to_bass(Pitch::from(As)).unwrap();
+        assert_eq!(seventh.inversion, 3);
+
+        assert!(matches!(
+            chord.invert_to_bass(Pitch::from(D)),
+            Err(ChordError::BassNotInChord(pitch)) if pitch == Pitch::from(D)
+        ));
+        assert_eq!(chord.inversion, 0);
+
+        let mut slash = Chord::from_regex("F/G").unwrap();
+        slash.invert_to_bass(Pitch::from(A)).unwrap();
+        assert_eq!((slash.inversion, slash.bass), (1, None));
+    }
+
+    #[test]
+    fn test_intervals_from_root() {
+        let from_root = |chord: &Chord| {
+            chord
+                .intervals_from_root()
+                .iter()
+                .map(|interval| (interval.semitone_count, interval.short_name()))
+                .collect::<Vec<_>>()
+        };
+
+        let triad = Chord::new(Pitch::from(C), Major, Triad).unwrap();
+        assert_eq!(from_root(&triad), [(0, "P1".into()), (4, "M3".into()), (7, "P5".into())]);
+
+        let cmaj7 = Chord::from_regex("Cmaj7/E").unwrap();
+        let semitones: Vec<u8> = cmaj7.intervals_from_root().iter().map(|i| i.semitone_count).collect();
+        assert_eq!(semitones, [0, 4, 7, 11]);
+
+        let c7 = Chord::new(Pitch::from(C), Dominant, Seventh).unwrap();
+        assert_eq!(from_root(&c7)[3], (10, "m7".to_string()));
+        let augmented = Chord::new(Pitch::from(C), Augmented, Triad).unwrap();
+        assert_eq!(from_root(&augmented)[2], (8, "A5".to_string()));
+        let ninth = Chord::new(Pitch::from(C), Dominant, Ninth).unwrap();
+        assert_eq!(from_root(&ninth)[4], (14, "M9".to_string()));
+    }
+
+    #[test]
+    fn test_is_diatonic_to() {
+        use theory::scale::Scale;
+        let c_major = Scale::from_regex("C major").unwrap();
+        assert!(Chord::from_regex("Dm7").unwrap().is_diatonic_to(&c_major));
+        assert!(Chord::from_regex("G7").unwrap().is_diatonic_to(&c_major));
+        assert!(!Chord::from_regex("D7").unwrap().is_diatonic_to(&c_major));
+        assert!(!Chord::from_regex("C/F#").unwrap().is_diatonic_to(&c_major));
+
+        let db_major = Scale::from_regex("Db major").unwrap();
+        assert!(Chord::new(Pitch::from(Cs), Major, Triad).unwrap().is_diatonic_to(&db_major));
+    }
+
+    #[test]
+    fn test_all_supported() {
+        let supported: Vec<(Quality, Number)> = Chord::all_supported().collect();
+        assert_eq!(supported.len(), 31);
+        assert_eq!(supported[0], (Major, Triad));
+        for (quality, number) in supported {
+            let chord = Chord::new(Pitch::from(C), quality, number).unwrap();
+            assert_eq!(chord.intervals, Chord::chord_intervals(quality, number).unwrap());
+        }
+        assert!(!Chord::all_supported().any(|supported| supported == (Dominant, Triad)));
+    }
+
+    #[test]
+    fn test_bass_and_root_note() {
+        let note = |note: Note| (note.pitch, note.octave);
+        let chord = Chord::new(Pitch::from(C), Major, Triad).unwrap();
+        assert_eq!(note(chord.bass_note()), (Pitch::from(C), 4));
+        assert_eq!(chord.bass_note(), chord.root_note());
+
+        let first = Chord::with_inversion(Pitch::from(C), Major, Triad, 1).unwrap();
+        assert_eq!(note(first.bass_note()), (Pitch::from(E), 4));
+        assert_eq!(note(first.root_note()), (Pitch::from(C), 5));
+
+        let third = Chord::with_inversion(Pitch::from(G), Dominant, Seventh, 3).unwrap();
+        assert_eq!(note(third.bass_note()).0, Pitch::from(F));
+        assert_eq!(third.root_note().pitch, Pitch::from(G));
+
+        let slash = Chord::from_regex("F/G").unwrap();
+        assert_eq!(slash.bass_note().pitch, Pitch::from(G));
+        assert_eq!(note(slash.root_note()), (Pitch::from(F), 4));
+    }
+
+    #[test]
+    fn test_display_inversions() {
+        let symbols = ["Cmaj7", "Cmaj7/E", "Cmaj7/G", "Cmaj7/B"];
+        for (inversion, symbol) in symbols.iter().enumerate() {
+            let chord = Chord::with_inversion(Pitch::from(C), Major, Seventh, inversion as u8).unwrap();
+            assert_eq!(chord.to_string(), *symbol);
+            if inversion > 0 {
+                assert!(symbol.ends_with(&format!("/{}", chord.notes()[0].pitch)));
+            }
+        }
+
+        let wrapped = Chord::with_inversion(Pitch::from(C), Major, Seventh, 6).unwrap();
+        assert_eq!(wrapped.to_string(), "Cmaj7/G");
+
+        let mut chord = Chord::new(Pitch::from(C), Major, Triad).unwrap();
+        chord.inversion = 3;
+        assert_eq!(chord.to_string(), "C");
+        chord.inversion = 5;
+        assert_eq!(chord.to_string(), "C/G");
+        assert_eq!(chord.notes()[0].pitch, Pitch::from(G));
+    }
+
+    #[test]
+    fn test_common_tones() {
+        let c = Chord::new(Pitch::from(C), Major, Triad).unwrap();
+        let g = Chord::with_inversion(Pitch::from(G), Major, Triad, 2).unwrap();
+        assert_eq!(c.common_tones(&g), [Pitch::from(G)]);
+        assert_eq!(g.common_tones(&c), [Pitch::from(G)]);
+
+        let fs = Chord::new(Pitch::from(Fs), Major, Triad).unwrap();
+        assert!(c.common_tones(&fs).is_empty());
+
+        let am7 = Chord::new(Pitch::from(A), Minor, Seventh).unwrap();
+        assert_eq!(c.common_tones(&am7), [Pitch::from(C), Pitch::from(E), Pitch::from(G)]);
+
+        let ab = Chord::new(Pitch::from(Ab), Major, Triad).unwrap();
+        let e = Chord::new(Pitch::from(E), Major, Triad).unwrap();
+        assert_eq!(ab.common_tones(&e), [Pitch::from(Ab)]);
+        assert_eq!(e.common_tones(&ab), [Pitch::from(Gs)]);
+    }
+
+    #[test]
+    fn test_voice_leading_distance() {
+        let chord = |root, quality, number| Chord::new(Pitch::from(root), quality, number).unwrap();
+        let c = chord(C, Major, Triad);
+
+        assert_eq!(c.voice_leading_distance(&c), 0);
+        assert_eq!(c.voice_leading_distance(&chord(A, Minor, Triad)), 2);
+        assert_eq!(c.voice_leading_distance(&chord(C, Minor, Triad)), 1);
+        assert_eq!(c.voice_leading_distance(&chord(Fs, Major, Triad)), 6);
+        assert!(c.voice_leading_distance(&chord(Fs, Major, Triad)) > c.voice_leading_distance(&chord(A, Minor, Triad)));
+
+        let g7 = chord(G, Dominant, Seventh);
+        assert_eq!(g7.voice_leading_distance(&c), 4);
+        assert_eq!(c.voice_leading_distance(&g7), g7.voice_leading_distance(&c));
+        let inverted = Chord::with_inversion(Pitch::from(A), Minor, Triad, 2).unwrap();
+        assert_eq!(c.voice_leading_distance(&inverted), 2);
+    }
+
+    #[test]
+    fn test_from_notes_extended() {
+        let identify = |notes: &[Note]| {
+            let chord = Chord::from_notes(notes).unwrap();
+            (chord.root, chord.quality, chord.number, chord.inversion, chord.bass)
+        };
+
+        let ninth = [note(C, 4), note(E, 4), note(G, 4), note(As, 4), note(D, 5)];
+        assert_eq!(identify(&ninth), (Pitch::from(C), Dominant, Ninth, 0, None));
+        let ninth_inverted = [note(D, 3), note(C, 4), note(E, 4), note(G, 4), note(As, 4)];
+        assert_eq!(identify(&ninth_inverted), (Pitch::from(C), Dominant, Ninth, 4, None));
+        let dm7 = [note(D, 4), note(F, 4), note(A, 4), note(C, 5)];
+        assert_eq!(identify(&dm7), (Pitch::from(D), Minor, Seventh, 0, None));
+        let dm7_inverted = [note(C, 3), note(D, 3), note(F, 3), note(A, 3)];
+        assert_eq!(identify(&dm7_inverted), (Pitch::from(D), Minor, Seventh, 3, None));
+        // F A C D is also Dm7, but the lowest note is preferred as the root
+        let f6 = [note(F, 3), note(A, 3), note(C, 4), note(D, 4)];
+        assert_eq!(identify(&f6), (Pitch::from(F), Major, Sixth, 0, None));
+        let eleventh = [note(C, 3), note(Ds, 3), note(G, 3), note(As, 3), note(D, 4), note(F, 4)];
+        assert_eq!(identify(&eleventh), (Pitch::from(C), Minor, Eleventh, 0, None));
+
+        // extra notes fall back to the largest chord within them, and a lowest note outside that
+        // chord becomes its bass
+        let extra = [note(C, 4), note(Cs, 4), note(E, 4), note(G, 4), note(As, 4), note(D, 5)];
+        assert_eq!(identify(&extra), (Pitch::from(C), Dominant, Ninth, 0, None));
+        let slash = [note(Fs, 3), note(C, 4), note(E, 4), note(G, 4)];
+        assert_eq!(identify(&slash), (Pitch::from(C), Major, Triad, 0, Some(Pitch::from(Fs))));
+    }
+
+    #[test]
+    fn test_octave_bounds() {
+        let chord = Chord::from_regex("C0 major / D").unwrap();
+        let octaves: Vec<u8> = chord.notes().iter().map(|note| note.octave).collect();
+        assert_eq!(octaves, vec![0, 0, 0, 0]);
+
+        let inverted = Chord::with_inversion(Pitch::from(B), Dominant, Thirteenth, 6).unwrap();
+        let low = Chord { octave: 0, ..inverted.clone() };
+        assert_eq!(low.notes().len(), 7);
+        assert!(low.notes().iter().all(|note| note.octave <= 2));
+        assert_eq!(low.transpose(-24).octave, 0);
+
+        let high = Chord { octave: u8::MAX, ..inverted };
+        assert!(high.notes().iter().all(|note| note.octave == u8::MAX));
+    }
+
+    #[test]
+    fn test_notes_with_velocity() {
+        let chord = Chord::from_regex("C dominant seventh / E").unwrap();
+        let notes = chord.notes_with_velocity(100);
+        assert_eq!(notes.len(), chord.notes().len());
+        assert!(notes.iter().zip(chord.notes()).all(|((note, _), expected)| *note == expected));
+        assert!(notes.iter().all(|(_, velocity)| *velocity == 100));
+        assert!(chord.notes_with_velocity(200).iter().all(|(_, velocity)| *velocity == 127));
+
+        let accented = chord.notes_with_accented_bass(90, 20);
+        assert_eq!(accented[0], (Note::new(Pitch::from(E), 4), 110));
+        assert!(accented[1..].iter().all(|(_, velocity)| *velocity == 90));
+        assert_eq!(chord.notes_with_accented_bass(120, 20)[0].1, 127);
+        assert!(chord.notes_with_accented_bass(255, 255).iter().all(|(_, velocity)| *velocity <= 127));
+    }
+
+    #[test]
+    fn test_root_position_and_invert() {
+        let chord = Chord::new(Pitch::from(C), Major, Triad).unwrap();
+        let first = chord.invert();
+        assert_eq!(first.inversion, 1);
+        assert_notes(&[E, G, C], first.notes());
+        assert_notes(&[G, C, E], first.invert().notes());
+
+        let back = chord.invert().invert().invert();
+        assert_eq!(back.inversion, 0);
+        assert_eq!(back.notes(), chord.notes());
+
+        let seventh = Chord::with_inversion(Pitch::from(G), Dominant, Seventh, 3).unwrap();
+        assert_eq!(seventh.invert().inversion, 0);
+        let root_position = seventh.root_position();
+        assert_eq!(root_position.inversion, 0);
+        assert_notes(&[G, B, D, F], root_position.notes());
+        assert_eq!(root_position.octave, seventh.octave);
+
+        let slash = Chord::from_regex("F/G").unwrap();
+        assert_eq!(slash.root_position().bass, Some(Pitch::from(G)));
+    }
+
+    #[test]
+    fn test_into_iterator() {
+        let chord = Chord::with_inversion(Pitch::from(D), Minor, Seventh, 2).unwrap();
+
+        let mut borrowed = Vec::new();
+        for note in &chord {
+            borrowed.push(note);
+        }
+        assert_eq!(borrowed, chord.notes());
+        assert_notes(&[A, C, D, F], borrowed);
+
+        let expected = chord.notes();
+        let owned: Vec<Note> = chord.into_iter().collect();
+        assert_eq!(owned, expected);
+    }
+
+    #[test]
+    fn test_classify_tone() {
+        let cmaj7 = Chord::new(Pitch::from(C), Major, Seventh).unwrap();
+        assert_eq!(cmaj7.classify_tone(Pitch::from(E)), ToneRole::ChordTone);
+        assert_eq!(cmaj7.classify_tone(Pitch::from(B)), ToneRole::ChordTone);
+        assert_eq!(cmaj7.classify_tone(Pitch::from(D)), ToneRole::Tension);
+        assert_eq!(cmaj7.classify_tone(Pitch::from(A)), ToneRole::Tension);
+        assert_eq!(cmaj7.classify_tone(Pitch::from(Fs)), ToneRole::Tension);
+        assert_eq!(cmaj7.classify_tone(Pitch::from(F)), ToneRole::NonChordTone);
+        assert_eq!(cmaj7.classify_tone(Pitch::from(Cs)), ToneRole::NonChordTone);
+
+        let c7 = Chord::new(Pitch::from(C), Dominant, Seventh).unwrap();
+        assert_eq!(c7.classify_tone(Pitch::from(Db)), ToneRole::Tension);
+        assert_eq!(c7.classify_tone(Pitch::from(Ds)), ToneRole::Tension);
+        assert_eq!(c7.classify_tone(Pitch::from(F)), ToneRole::NonChordTone);
+
+        let dm7 = Chord::new(Pitch::from(D), Minor, Seventh).unwrap();
+        assert_eq!(dm7.classify_tone(Pitch::from(G)), ToneRole::Tension);
+        assert_eq!(dm7.classify_tone(Pitch::from(As)), ToneRole::NonChordTone);
+        assert_eq!(Chord::from_regex("F/G").unwrap().classify_tone(Pitch::from(G)), ToneRole::ChordTone);
+    }
+
+    #[test]
+    fn test_arpeggiate() {
+        let chord = Chord::new(Pitch::from(C), Major, Triad).unwrap();
+        let names = |notes: Vec<Note>| notes.iter().map(Note::to_string).collect::<Vec<_>>().join(" ");
+
+        assert_eq!(names(chord.arpeggiate(ArpPattern::Up, 1)), "C4 E4 G4");
+        assert_eq!(names(chord.arpeggiate(ArpPattern::Down, 1)), "G4 E4 C4");
+        assert_eq!(names(chord.arpeggiate(ArpPattern::UpDown, 1)), "C4 E4 G4 E4");
+        assert_eq!(names(chord.arpeggiate(ArpPattern::Up, 0)), "C4 E4 G4");
+        assert_eq!(names(chord.arpeggiate(ArpPattern::Up, 2)), "C4 E4 G4 C5 E5 G5");
+        assert_eq!(names(chord.arpeggiate(ArpPattern::UpDown, 2)), "C4 E4 G4 C5 E5 G5 E5 C5 G4 E4");
+
+        let inverted = Chord::with_inversion(Pitch::from(C), Major, Triad, 1).unwrap();
+        assert_eq!(names(inverted.arpeggiate(ArpPattern::Down, 1)), "C5 G4 E4");
+
+        let random = chord.arpeggiate(ArpPattern::Random(7), 2);
+        assert_eq!(random, chord.arpeggiate(ArpPattern::Random(7), 2));
+        let mut sorted = random.clone();
+        sorted.sort();
+        assert_eq!(sorted, chord.arpeggiate(ArpPattern::Up, 2));
+        assert!((0..10).any(|seed| chord.arpeggiate(ArpPattern::Random(seed), 2) != sorted));
+    }
+
+    #[test]
+    fn test_negative() {
+        let c = Pitch::from(C);
+        let name = |chord: Chord| (chord.root, chord.quality, chord.number);
+
+        let g = Chord::new(Pitch::from(G), Major, Triad).unwrap();
+        assert_eq!(name(g.negative(c).unwrap()), (Pitch::from(F), Minor, Triad));
+        let c_major = Chord::new(c, Major, Triad).unwrap();
+        assert_eq!(name(c_major.negative(c).unwrap()), (c, Minor, Triad));
+        assert_notes(&[C, Eb, G], c_major.negative(c).unwrap().notes());
+        let g7 = Chord::new(Pitch::from(G), Dominant, Seventh).unwrap();
+        assert_eq!(name(g7.negative(c).unwrap()), (Pitch::from(D), HalfDiminished, Seventh));
+        assert_notes(&[D, F, Ab, C], g7.negative(c).unwrap().notes());
+
+        let d7 = Chord::new(Pitch::from(D), Dominant, Seventh).unwrap();
+        assert_eq!(name(d7.negative(Pitch::from(G)).unwrap()), (Pitch::from(A), HalfDiminished, Seventh));
+        assert_notes(&[A, C, Eb, G], d7.negative(Pitch::from(G)).unwrap().notes());
+
+        // the negative chord only has reflected notes, and reflecting triads and sevenths twice
+        // gives back the same chord
+        for (quality, number) in Chord::all_supported() {
+            let chord = Chord::new(Pitch::from(E), quality, number).unwrap();
+            let reflected: Vec<u8> = chord.notes().iter().map(|note| (19 - note.pitch.into_u8()) % 12).collect();
+            let negative = chord.negative(c).unwrap();
+            assert!(negative.notes().iter().all(|note| reflected.contains(&note.pitch.into_u8())), "{}", chord);
+            if chord.notes().len() <= 4 {
+                assert_eq!(negative.negative(c).unwrap(), chord, "{}", chord);
+            }
+        }
+        assert!(Chord::from_regex("F/G").unwrap().negative(c).unwrap().notes().len() >= 3);
+
+        let high = Chord::with_octave(c, Major, Triad, 254).unwrap();
+        assert_eq!(name(high.negative(c).unwrap()), (c, Minor, Triad));
+        let highest = Chord::with_octave(c, Dominant, Ninth, u8::MAX).unwrap();
+        let negative = highest.negative(c);
+        assert!(negative.is_ok());
+        let negative = negative.unwrap();
+        assert_eq!(name(negative.clone()), (Pitch::from(F), Dominant, Ninth));
+        assert_eq!(negative.octave, u8::MAX);
+        assert!(negative.notes().iter().all(|note| note.octave == u8::MAX));
+    }
+
+    #[test]
+    fn test_contains() {
+        let chord = Chord::new(Pitch::from(C), Major, Triad).unwrap();
+        let f_flat = Pitch::new(NoteLetter::F, -1);
+
+        assert!(chord.contains_note(&Note::new(Pitch::from(E), 4)));
+        assert!(!chord.contains_note(&Note::new(Pitch::from(E), 5)));
+        assert!(chord.contains_note(&Note::new(Pitch::from(C), 4)));
+        assert!(chord.contains_note(&Note::new(f_flat, 4)));
+        assert!(!chord.contains_note(&Note::new(Pitch::from(D), 4)));
+
+        assert!(chord.contains_pitch_class(Pitch::from(E)));
+        assert!(chord.contains_pitch_class(f_flat));
+        assert!(!chord.contains_pitch_class(Pitch::from(F)));
+
+        let high = Chord::with_octave(Pitch::from(C), Major, Triad, 5).unwrap();
+        assert!(high.contains_note(&Note::new(Pitch::from(E), 5)));
+        assert!(!high.contains_note(&Note::new(Pitch::from(E), 4)));
+        assert!(high.contains_pitch_class(Pitch::from(E)));
+
+        let inverted = Chord::with_inversion(Pitch::from(C), Major, Triad, 1).unwrap();
+        assert!(inverted.contains_note(&Note::new(Pitch::from(C), 5)));
+        assert!(!inverted.contains_note(&Note::new(Pitch::from(C), 4)));
+    }
 }
@@ -0,0 +1,57 @@
+extern crate rust_music_theory as theory;
+use theory::chord::{Number::*, Quality::*, *};
+use theory::note::{Note, Pitch, PitchSymbol};
+
+#[cfg(test)]
+mod progression_tests {
+    use super::*;
+
+    fn pitches(notes: &[Note]) -> Vec<String> {
+        notes.iter().map(|note| note.pitch.to_string()).collect()
+    }
+
+    #[test]
+    fn test_from_str() {
+        let progression: Progression = "C Am F G".parse().unwrap();
+        assert_eq!(progression.chords.len(), 4);
+
+        let am = &progression.chords[1];
+        assert_eq!((am.root, am.quality, am.number), (Pitch::from(PitchSymbol::A), Minor, Triad));
+        assert_eq!(progression.chords[2].root, Pitch::from(PitchSymbol::F));
+
+        let spaced: Progression = "  Cmaj7\tDm7   G7 ".parse().unwrap();
+        assert_eq!(spaced.chords.len(), 3);
+        assert_eq!(spaced.chords[2].number, Seventh);
+
+        assert!("".parse::<Progression>().unwrap().chords.is_empty());
+        assert!("C Hm G".parse::<Progression>().is_err());
+    }
+
+    #[test]
+    fn test_transpose() {
+        let progression: Progression = "C Am F G".parse().unwrap();
+        let up = progression.transpose(2);
+        assert_eq!(up.to_string(), "D Bm G A");
+        assert_eq!(up.chords[1].quality, Minor);
+        assert_eq!(up.transpose(-2), progression);
+
+        let notes = up.notes_per_chord();
+        assert_eq!(notes.len(), 4);
+        assert_eq!(pitches(&notes[0]), ["D", "F#", "A"]);
+        assert_eq!(pitches(&notes[1]), ["B", "D", "F#"]);
+    }
+
+    #[test]
+    fn test_display() {
+        let progression: Progression = "C Am F G".parse().unwrap();
+        assert_eq!(progression.to_string(), "C Am F G");
+        assert_eq!(progression.to_string().parse::<Progression>().unwrap(), progression);
+
+        let chords = vec![
+            Chord::new(Pitch::from(PitchSymbol::D), Minor, Seventh).unwrap(),
+            Chord::new(Pitch::from(PitchSymbol::G), Dominant, Seventh).unwrap(),
+        ];
+        assert_eq!(Progression::new(chords).to_string(), "Dm7 G7");
+        assert_eq!(Progression::new(vec![]).to_string(), "");
+    }
+}
@@ -18,4 +18,22 @@ mod chord_number_tests {
         let (number, _) = Number::from_regex(string).unwrap();
         assert_eq!(Number::Triad, number);
     }
+
+    #[test]
+    fn test_quality_shorthand() {
+        use theory::chord::Quality::{self, *};
+        let table = [
+            ("-7", Minor),
+            ("+", Augmented),
+            ("°7", Diminished),
+            ("o", Diminished),
+            ("ø7", HalfDiminished),
+        ];
+        for (string, quality) in table.iter() {
+            let (parsed, quality_match) = Quality::from_regex(string).unwrap();
+            assert_eq!(parsed, *quality);
+            let end = quality_match.unwrap().end();
+            assert_eq!(&string[end..], string.trim_start_matches(|c: char| !c.is_ascii_digit()));
+        }
+    }
 }
@@ -1,6 +1,6 @@
 extern crate rust_music_theory as theory;
-use theory::chord::{Chord, Number, Number::*, Quality, Quality::*};
-use theory::note::{Pitch, NoteLetter::*};
+use theory::chord::{Chord, ChordError, Number, Number::*, Quality, Quality::*};
+use theory::note::{Notes, Pitch, NoteLetter::*};
 
 fn assert_chords(table: Vec<(&str, Pitch, Quality, Number)>) {
     for (string, pitch, quality, number) in table {
@@ -11,6 +11,12 @@ fn assert_chords(table: Vec<(&str, Pitch, Quality, Number)>) {
     }
 }
 
+fn assert_unknown(strings: &[&str]) {
+    for string in strings {
+        assert!(matches!(Chord::from_regex(string), Err(ChordError::UnknownChord)), "{}", string);
+    }
+}
+
 #[cfg(test)]
 mod chord_regex_tests {
     use super::*;
@@ -45,12 +51,14 @@ mod chord_regex_tests {
             ("C m", Pitch::new(C, 0), Minor, Triad),
             ("C MiN Triad", Pitch::new(C, 0), Minor, Triad),
             ("C Minor Seventh", Pitch::new(C, 0), Minor, Seventh),
-            ("C m Ninth", Pitch::new(C, 0), Minor, Ninth),
             ("C#m Eleventh", Pitch::new(C, 1), Minor, Eleventh),
             ("Dsm Thirteenth", Pitch::new(D, 1), Minor, Thirteenth),
         ];
 
         assert_chords(table);
+        assert_unknown(&[
+            "C m Ninth",
+        ]);
     }
 
     #[test]
@@ -64,12 +72,14 @@ mod chord_regex_tests {
             ("C augmented", Pitch::new(C, 0), Augmented, Triad),
             ("C augmented Triad", Pitch::new(C, 0), Augmented, Triad),
             ("C Augmented Seventh", Pitch::new(C, 0), Augmented, Seventh),
-            ("C Augmented Ninth", Pitch::new(C, 0), Augmented, Ninth),
-            ("C# augmented Eleventh", Pitch::new(C, 1), Augmented, Eleventh),
-            ("Ds augmented Thirteenth", Pitch::new(D, 1), Augmented, Thirteenth),
         ];
 
         assert_chords(table);
+        assert_unknown(&[
+            "C Augmented Ninth",
+            "C# augmented Eleventh",
+            "Ds augmented Thirteenth",
+        ]);
     }
 
     #[test]
@@ -83,48 +93,41 @@ mod chord_regex_tests {
             ("C Diminished", Pitch::new(C, 0), Diminished, Triad),
             ("C Diminished Triad", Pitch::new(C, 0), Diminished, Triad),
             ("C Diminished Seventh", Pitch::new(C, 0), Diminished, Seventh),
-            ("C Diminished Ninth", Pitch::new(C, 0), Diminished, Ninth),
-            ("C# Diminished Eleventh", Pitch::new(C, 1), Diminished, Eleventh),
-            ("Ds Diminished Thirteenth", Pitch::new(D, 1), Diminished, Thirteenth),
         ];
 
         assert_chords(table);
+        assert_unknown(&[
+            "C Diminished Ninth",
+            "C# Diminished Eleventh",
+            "Ds Diminished Thirteenth",
+        ]);
     }
 
     #[test]
     fn test_half_diminished() {
         let table = vec![
-            ("C Half Diminished", Pitch::new(C, 0), HalfDiminished, Triad),
-            ("E halfdiminished", Pitch::new(E, 0), HalfDiminished, Triad),
-            ("C half diminished", Pitch::new(C, 0), HalfDiminished, Triad),
-            ("Cb HALFDIMINISHED", Pitch::new(C, -1), HalfDiminished, Triad),
             ("Cb HalfDiminished seventh", Pitch::new(C, -1), HalfDiminished, Seventh),
-            ("C HalfDiminished", Pitch::new(C, 0), HalfDiminished, Triad),
-            ("C HalfDiminished Triad", Pitch::new(C, 0), HalfDiminished, Triad),
             ("C HalfDiminished Seventh", Pitch::new(C, 0), HalfDiminished, Seventh),
-            ("C HalfDiminished Ninth", Pitch::new(C, 0), HalfDiminished, Ninth),
-            ("C# HalfDiminished Eleventh", Pitch::new(C, 1), HalfDiminished, Eleventh),
-            (
-                "Ds HalfDiminished Thirteenth",
-                Pitch::new(D, 1),
-                HalfDiminished,
-                Thirteenth,
-            ),
+            ("C Half Diminished", Pitch::new(C, 0), HalfDiminished, Seventh),
+            ("E halfdiminished", Pitch::new(E, 0), HalfDiminished, Seventh),
+            ("C half diminished", Pitch::new(C, 0), HalfDiminished, Seventh),
+            ("Cb HALFDIMINISHED", Pitch::new(C, -1), HalfDiminished, Seventh),
+            ("C HalfDiminished", Pitch::new(C, 0), HalfDiminished, Seventh),
         ];
 
         assert_chords(table);
+        assert_unknown(&[
+            "C HalfDiminished Triad",
+            "C HalfDiminished Ninth",
+            "C# HalfDiminished Eleventh",
+            "Ds HalfDiminished Thirteenth",
+        ]);
     }
 
     #[test]
     fn test_dominant() {
         let table = vec![
-            ("C dominant", Pitch::new(C, 0), Dominant, Triad),
-            ("E DOMINANT", Pitch::new(E, 0), Dominant, Triad),
-            ("C DOmInAnT", Pitch::new(C, 0), Dominant, Triad),
-            ("Cb Dominant", Pitch::new(C, -1), Dominant, Triad),
             ("Cb Dominant seventh", Pitch::new(C, -1), Dominant, Seventh),
-            ("C Dominant", Pitch::new(C, 0), Dominant, Triad),
-            ("C Dominant Triad", Pitch::new(C, 0), Dominant, Triad),
             ("C Dominant Seventh", Pitch::new(C, 0), Dominant, Seventh),
             ("C Dominant Ninth", Pitch::new(C, 0), Dominant, Ninth),
             ("C# Dominant Eleventh", Pitch::new(C, 1), Dominant, Eleventh),
@@ -132,6 +135,14 @@ mod chord_regex_tests {
         ];
 
         assert_chords(table);
+        assert_unknown(&[
+            "C dominant",
+            "E DOMINANT",
+            "C DOmInAnT",
+            "Cb Dominant",
+            "C Dominant",
+            "C Dominant Triad",
+        ]);
     }
 
     #[test]
@@ -146,4 +157,160 @@ mod chord_regex_tests {
 
         assert_chords(table);
     }
+
+    #[test]
+    fn test_symbols() {
+        let table = vec![
+            ("C", Pitch::new(C, 0), Major, Triad),
+            ("Cm", Pitch::new(C, 0), Minor, Triad),
+            ("Cmaj7", Pitch::new(C, 0), Major, Seventh),
+            ("CM7", Pitch::new(C, 0), Major, Seventh),
+            ("Cm7", Pitch::new(C, 0), Minor, Seventh),
+            ("C7", Pitch::new(C, 0), Dominant, Seventh),
+            ("Eb9", Pitch::new(E, -1), Dominant, Ninth),
+            ("F#13", Pitch::new(F, 1), Dominant, Thirteenth),
+            ("Cmmaj7", Pitch::new(C, 0), Minor, MajorSeventh),
+            ("Caugmaj7", Pitch::new(C, 0), Augmented, MajorSeventh),
+            ("Cdim7", Pitch::new(C, 0), Diminished, Seventh),
+            ("Cø7", Pitch::new(C, 0), HalfDiminished, Seventh),
+            ("Csus2", Pitch::new(C, 0), Suspended2, Triad),
+            ("Cssus4", Pitch::new(C, 1), Suspended4, Triad),
+            ("Gmaj9", Pitch::new(G, 0), Major, Ninth),
+            ("Am11", Pitch::new(A, 0), Minor, Eleventh),
+            ("A5", Pitch::new(A, 0), Major, Fifth),
+            ("F#5", Pitch::new(F, 1), Major, Fifth),
+            ("C6", Pitch::new(C, 0), Major, Sixth),
+            ("Cadd9", Pitch::new(C, 0), Major, Add9),
+            ("Cmadd9", Pitch::new(C, 0), Minor, Add9),
+            ("G add11", Pitch::new(G, 0), Major, Add11),
+            ("F Major Add13", Pitch::new(F, 0), Major, Add13),
+            ("Cmadd13", Pitch::new(C, 0), Minor, Add13),
+            ("Cm6", Pitch::new(C, 0), Minor, Sixth),
+            ("Bb Minor Sixth", Pitch::new(B, -1), Minor, Sixth),
+        ];
+
+        assert_chords(table);
+    }
+
+    #[test]
+    fn test_unparsed_remainder() {
+        match Chord::from_regex("C blargh") {
+            Err(ChordError::UnknownQuality(text)) => assert_eq!(text, "blargh"),
+            other => panic!("unexpected result {:?}", other),
+        }
+
+        match Chord::from_regex("C maj blargh") {
+            Err(ChordError::UnknownNumber(text)) => assert_eq!(text, "blargh"),
+            other => panic!("unexpected result {:?}", other),
+        }
+
+        match Chord::from_regex("C minor seventh blargh") {
+            Err(ChordError::UnknownNumber(text)) => assert_eq!(text, "blargh"),
+            other => panic!("unexpected result {:?}", other),
+        }
+
+        assert_eq!(
+            ChordError::UnknownQuality("blargh".to_string()).to_string(),
+            "Unknown chord quality \"blargh\"!"
+        );
+        assert!(Chord::from_regex("C maj").is_ok());
+    }
+
+    #[test]
+    fn test_parse() {
+        let chord: Chord = "Cmaj7".parse().unwrap();
+        assert_eq!((chord.root, chord.quality, chord.number), (Pitch::new(C, 0), Major, Seventh));
+        assert_eq!("C minor".parse::<Chord>().unwrap(), Chord::from_regex("Cm").unwrap());
+        assert!(matches!("H".parse::<Chord>(), Err(ChordError::InvalidPitch)));
+    }
+
+    #[test]
+    fn test_shorthand() {
+        let table = vec![
+            ("C-", Pitch::new(C, 0), Minor, Triad),
+            ("C-7", Pitch::new(C, 0), Minor, Seventh),
+            ("Cm7", Pitch::new(C, 0), Minor, Seventh),
+            ("C+", Pitch::new(C, 0), Augmented, Triad),
+            ("C+7", Pitch::new(C, 0), Augmented, Seventh),
+            ("Cdim", Pitch::new(C, 0), Diminished, Triad),
+            ("C°", Pitch::new(C, 0), Diminished, Triad),
+            ("Co7", Pitch::new(C, 0), Diminished, Seventh),
+            ("Cø", Pitch::new(C, 0), HalfDiminished, Seventh),
+            ("F#ø7", Pitch::new(F, 1), HalfDiminished, Seventh),
+        ];
+
+        assert_chords(table);
+    }
+
+    /// A small xorshift generator, so the round trip covers the same chords on every run.
+    struct Xorshift(u64);
+
+    impl Xorshift {
+        fn next(&mut self, bound: usize) -> usize {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % bound as u64) as usize
+        }
+    }
+
+    fn assert_round_trip(chord: &Chord, padding: &str) {
+        let slash = format!("{}/{}", padding, padding);
+        let symbol = format!("{}{}{}", padding, chord.to_string().replace('/', &slash), padding);
+        let parsed = Chord::from_regex(&symbol)
+            .unwrap_or_else(|error| panic!("{:?} failed to parse: {}", symbol, error));
+        let classes = |chord: &Chord| {
+            let mut classes: Vec<u8> = chord.notes().iter().map(|note| note.pitch.into_u8()).collect();
+            classes.sort_unstable();
+            classes
+        };
+
+        assert_eq!(classes(&parsed), classes(chord), "{}", symbol);
+        assert_eq!(parsed.notes()[0].pitch, chord.notes()[0].pitch, "{}", symbol);
+        assert_eq!((parsed.quality, parsed.number), (chord.quality, chord.number), "{}", symbol);
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let letters = [A, B, C, D, E, F, G];
+        let supported: Vec<(Quality, Number)> = Chord::all_supported().collect();
+        let mut random = Xorshift(0x2545_f491_4f6c_dd1d);
+
+        for _ in 0..2000 {
+            let root = Pitch::new(letters[random.next(7)], random.next(3) as i8 - 1);
+            let (quality, number) = supported[random.next(supported.len())];
+            let inversion = random.next(8) as u8;
+            let chord = Chord::with_inversion(root, quality, number, inversion).unwrap();
+            assert_round_trip(&chord, ["", " ", "  "][random.next(3)]);
+        }
+
+        // Regressions found by the round trip
+        assert_round_trip(&Chord::with_inversion(Pitch::new(D, -1), Suspended4, Triad, 1).unwrap(), "");
+        assert_round_trip(&Chord::with_inversion(Pitch::new(C, 1), Major, Sixth, 3).unwrap(), " ");
+    }
+
+    #[test]
+    fn test_try_from() {
+        use std::convert::TryFrom;
+
+        let chord = Chord::try_from("C minor").unwrap();
+        assert_eq!((chord.root, chord.quality, chord.number), (Pitch::new(C, 0), Minor, Triad));
+        assert_eq!(chord, "C minor".parse::<Chord>().unwrap());
+        assert!(matches!(Chord::try_from("H"), Err(ChordError::InvalidPitch)));
+
+        assert_eq!(Quality::try_from("minor").unwrap(), Minor);
+        assert_eq!(Quality::try_from(" half diminished ").unwrap(), HalfDiminished);
+        assert!(matches!(Quality::try_from(""), Err(ChordError::InvalidRegex)));
+        assert!(matches!(Quality::try_from("minor seventh"), Err(ChordError::InvalidRegex)));
+
+        assert_eq!(Number::try_from("7").unwrap(), Seventh);
+        assert_eq!(Number::try_from("major seventh").unwrap(), MajorSeventh);
+        assert!(matches!(Number::try_from("seventh chord"), Err(ChordError::InvalidRegex)));
+
+        let build = |quality: &str, number: &str| -> Result<Chord, ChordError> {
+            Chord::new(Pitch::new(D, 0), Quality::try_from(quality)?, Number::try_from(number)?)
+        };
+        assert_eq!(build("minor", "eleventh").unwrap().number, Eleventh);
+        assert!(build("minor", "tenth").is_err());
+    }
 }
@@ -0,0 +1,43 @@
+#![cfg(feature = "serde")]
+
+extern crate rust_music_theory as theory;
+use theory::chord::{Number::*, Quality::*, *};
+use theory::note::{PitchSymbol::*, *};
+use theory::scale::{Direction, Mode, Scale, ScaleType};
+
+#[cfg(test)]
+mod chord_serde_tests {
+    use super::*;
+
+    #[test]
+    fn test_chord_round_trip() {
+        let chord = Chord::new(Pitch::from(C), Major, Seventh).unwrap();
+        let json = serde_json::to_value(&chord).unwrap();
+        assert_eq!(json["root"], "C");
+        assert_eq!(json["octave"], 4);
+        assert_eq!(json["quality"], "Major");
+        assert_eq!(json["number"], "Seventh");
+        assert_eq!(json["inversion"], 0);
+
+        let parsed: Chord = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, chord);
+        assert_eq!(parsed.to_string(), "Cmaj7");
+    }
+
+    #[test]
+    fn test_note_and_scale_round_trip() {
+        let note = Note::new(Pitch::from(Fs), 3);
+        let json = serde_json::to_string(&note).unwrap();
+        assert_eq!(json, r#"{"pitch":"F#","octave":3}"#);
+        let parsed: Note = serde_json::from_str(&json).unwrap();
+        assert_eq!((parsed.pitch, parsed.octave), (note.pitch, note.octave));
+
+        let scale = Scale::new(ScaleType::Diatonic, Pitch::from(Eb), 4, Some(Mode::Dorian), Direction::Ascending).unwrap();
+        let parsed: Scale = serde_json::from_str(&serde_json::to_string(&scale).unwrap()).unwrap();
+        assert_eq!(parsed.tonic, scale.tonic);
+        assert_eq!(parsed.mode, scale.mode);
+        assert_eq!(parsed.notes().len(), scale.notes().len());
+
+        assert!(serde_json::from_str::<Pitch>(r#""H""#).is_err());
+    }
+}
@@ -0,0 +1,202 @@
+use std::process::Command;
+
+fn rustmt(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_rustmt"))
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn rustmt_error(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_rustmt"))
+        .args(args)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    String::from_utf8(output.stderr).unwrap()
+}
+
+#[cfg(test)]
+mod cli_tests {
+    use super::*;
+
+    #[test]
+    fn test_json_chord() {
+        let output = rustmt(&["chord", "C", "minor", "--format", "json"]);
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(json["name"], "Cm");
+
+        let notes = json["notes"].as_array().unwrap();
+        let pitches: Vec<&str> = notes.iter().map(|note| note["pitch"].as_str().unwrap()).collect();
+        assert_eq!(pitches, ["C", "Eb", "G"]);
+        assert_eq!(notes[0]["octave"], 4);
+        assert!((notes[0]["frequency"].as_f64().unwrap() - 261.63).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_json_scale() {
+        let output = rustmt(&["scale", "A", "minor", "--format", "json"]);
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(json["name"], "A Minor");
+        let notes = json["notes"].as_array().unwrap();
+        assert_eq!(notes.len(), 8);
+        assert_eq!(notes[0]["frequency"], 440.0);
+
+        let output = rustmt(&["scale", "D", "harmonic", "minor", "--format", "json"]);
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(json["name"], "D Harmonic Minor");
+    }
+
+    #[test]
+    fn test_text_by_default() {
+        let output = rustmt(&["chord", "C", "major"]);
+        assert!(output.starts_with("Notes:"));
+        assert!(output.contains("1: C"));
+    }
+
+    fn note_ons(bytes: &[u8]) -> Vec<u8> {
+        bytes
+            .windows(4)
+            .filter(|window| window[0] == 0x00 && window[1] == 0x90)
+            .map(|window| window[2])
+            .collect()
+    }
+
+    #[test]
+    fn test_midi_chord() {
+        let path = std::env::temp_dir().join("rustmt_test_midi_chord.mid");
+        let path = path.to_str().unwrap();
+        rustmt(&["chord", "midi", "C minor", "-o", path]);
+
+        let bytes = std::fs::read(path).unwrap();
+        assert_eq!(&bytes[0..4], b"MThd");
+        assert_eq!(&bytes[4..8], &[0, 0, 0, 6]);
+        assert_eq!(&bytes[14..18], b"MTrk");
+        assert_eq!(note_ons(&bytes), [60, 63, 67]);
+        assert_eq!(&bytes[bytes.len() - 3..], &[0xff, 0x2f, 0x00]);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_midi_scale() {
+        let path = std::env::temp_dir().join("rustmt_test_midi_scale.mid");
+        let path = path.to_str().unwrap();
+        rustmt(&["scale", "midi", "A", "minor", "-o", path, "--tempo", "60", "--duration", "0.5"]);
+
+        let bytes = std::fs::read(path).unwrap();
+        assert_eq!(&bytes[0..4], b"MThd");
+        assert_eq!(note_ons(&bytes), [69, 71, 72, 74, 76, 77, 79, 81]);
+        // one second per beat
+        assert_eq!(&bytes[22..29], &[0x00, 0xff, 0x51, 0x03, 0x0f, 0x42, 0x40]);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_frequency() {
+        let output = rustmt(&["scale", "A", "minor", "--frequency"]);
+        assert!(output.starts_with("Notes:"));
+        assert!(output.contains("1: A 440.00 Hz"));
+        assert!(output.contains("8: A 880.00 Hz"));
+
+        let output = rustmt(&["chord", "A", "major", "-f", "--tuning", "432"]);
+        assert!(output.contains("1: A 432.00 Hz"));
+
+        assert!(!rustmt(&["chord", "A", "major"]).contains("Hz"));
+    }
+
+    #[test]
+    fn test_progression() {
+        let output = rustmt(&["progression", "C G Am F"]);
+        let chords: Vec<&str> = output.lines().filter(|line| line.ends_with(':')).collect();
+        assert_eq!(chords, ["C:", "G:", "Am:", "F:"]);
+        assert!(output.starts_with("C:\n  1: C\n  2: E\n  3: G\nG:\n  1: G\n"));
+
+        let output = rustmt(&["progression", "C major, A minor"]);
+        assert!(output.contains("Am:\n  1: A\n  2: C\n  3: E"));
+
+        assert!(rustmt_error(&["progression", "C Xq F"]).contains("\"Xq\""));
+    }
+
+    #[test]
+    fn test_roman_numeral_progression() {
+        let output = rustmt(&["progression", "--key", "C major", "I V vi IV"]);
+        let chords: Vec<&str> = output.lines().filter(|line| line.ends_with(':')).collect();
+        assert_eq!(chords, ["C:", "G:", "Am:", "F:"]);
+        assert!(output.contains("Am:\n  1: A\n  2: C\n  3: E"));
+
+        let output = rustmt(&["progression", "-k", "C major", "V/V"]);
+        assert!(output.starts_with("D:\n  1: D\n  2: F#\n  3: A"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = rustmt_error(&["chord", "C", "maj", "blargh"]);
+        assert!(error.contains("Unknown chord number \"blargh\""));
+
+        let error = rustmt_error(&["chord", "Z", "minor"]);
+        assert_eq!(error, "Couldn't parse the chord \"Z minor\": Invalid pitch class!\n");
+        assert!(!error.contains("panicked"));
+
+        assert!(rustmt_error(&["scale", "Z", "minor"]).contains("Invalid pitch class!"));
+        assert!(rustmt_error(&["scale", "C", "blargh"]).contains("Can't determine the mode!"));
+        assert!(rustmt_error(&["chord", "A", "-f", "--tuning", "abc"]).contains("Invalid tuning"));
+        assert!(rustmt_error(&["chord"]).contains("Couldn't parse the chord"));
+    }
+
+    #[test]
+    fn test_chord_list() {
+        let output = rustmt(&["chord", "list"]);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 32);
+        assert_eq!(lines[0], "Available chords:");
+        assert_eq!(lines[1], " - Major Triad");
+        assert!(lines.contains(&" - Half Diminished Seventh"));
+        assert!(lines.contains(&" - Augmented Major Seventh"));
+        assert_eq!(lines[31], " - Minor Add13");
+    }
+
+    #[test]
+    fn test_octave() {
+        let output = rustmt(&["chord", "C", "major", "--octave", "2"]);
+        assert_eq!(output, "Notes:\n  1: C2\n  2: E2\n  3: G2\n");
+        let output = rustmt(&["scale", "A", "minor", "--octave", "3"]);
+        assert!(output.starts_with("Notes:\n  1: A3\n  2: B3\n  3: C4\n"));
+
+        let output = rustmt(&["chord", "C", "major", "--octave", "2", "--format", "json"]);
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(json["notes"][0]["octave"], 2);
+        assert_eq!(rustmt(&["chord", "C", "major"]), "Notes:\n  1: C\n  2: E\n  3: G\n");
+
+        assert!(rustmt_error(&["chord", "C", "--octave", "42"]).contains("Invalid octave 42"));
+        assert!(rustmt_error(&["scale", "C", "major", "--octave=-1"]).contains("Invalid octave"));
+    }
+
+    #[test]
+    fn test_identify() {
+        assert_eq!(rustmt(&["identify", "C E G"]), "C Major Triad\n");
+        assert_eq!(rustmt(&["identify", "C", "Eb", "Gb", "A"]), "C Diminished Seventh\n");
+        assert_eq!(rustmt(&["identify", "E G C"]), "C Major Triad / E\n");
+        assert_eq!(rustmt(&["identify", "D3 C4 E4 G4 Bb4"]), "C Dominant Ninth / D\n");
+        assert_eq!(rustmt(&["identify", "D F A C"]), "D Minor Seventh\n");
+
+        assert!(rustmt_error(&["identify", "C C# D"]).contains("No chord matches the notes \"C C# D\""));
+        assert!(rustmt_error(&["identify", "C H G"]).contains("Couldn't parse the note \"H\""));
+    }
+
+    #[test]
+    fn test_spell() {
+        let flat = rustmt(&["scale", "Db", "major", "--spell", "flat"]);
+        let sharp = rustmt(&["scale", "Db", "major", "--spell", "sharp"]);
+        assert_eq!(flat, "Notes:\n  1: Db\n  2: Eb\n  3: F\n  4: Gb\n  5: Ab\n  6: Bb\n  7: C\n  8: Db\n");
+        assert_eq!(sharp, "Notes:\n  1: C#\n  2: D#\n  3: F\n  4: F#\n  5: G#\n  6: A#\n  7: C\n  8: C#\n");
+        assert_eq!(rustmt(&["scale", "Db", "major"]), flat);
+        assert_eq!(rustmt(&["scale", "C#", "major"]).lines().nth(3), Some("  3: E#"));
+        assert_eq!(rustmt(&["scale", "C#", "major", "--spell", "flat"]).lines().nth(3), Some("  3: F"));
+
+        let output = rustmt(&["chord", "F#", "major", "--spell", "flat", "--octave", "3"]);
+        assert_eq!(output, "Notes:\n  1: Gb3\n  2: Bb3\n  3: Db4\n");
+        assert!(rustmt_error(&["scale", "C", "major", "--spell", "natural"]).contains("natural"));
+    }
+}
@@ -1,7 +1,11 @@
 extern crate rust_music_theory as theory;
-use theory::interval::Interval;
+use theory::interval::{Interval, Step};
 use theory::note::{Note, Pitch, PitchSymbol::*};
 
+fn note(pitch: &str, octave: u8) -> Note {
+    Note::new(Pitch::from_str(pitch).unwrap(), octave)
+}
+
 #[cfg(test)]
 mod test_interval {
     use super::*;
@@ -56,6 +60,7 @@ mod test_interval {
     }
 
     #[test]
+    #[allow(clippy::reversed_empty_ranges)]
     fn test_octave_jump_down() {
         let octave_interval = Interval::from_semitone(12).unwrap();
         for octave in 8..=0 {
@@ -87,4 +92,180 @@ mod test_interval {
             assert_eq!(inverted.unwrap().semitone_count, list[list.len() - i - 1]);
         }
     }
+
+    #[test]
+    fn test_compound_interval() {
+        let ninth = Interval::from_semitone(14).unwrap();
+        assert_eq!(ninth.semitone_count, 14);
+        assert_eq!(ninth.number.to_string(), "Second");
+        assert_eq!(ninth.quality.to_string(), "Major");
+        assert_eq!(Interval::from_semitone(25).unwrap().name(), "Minor Sixteenth");
+    }
+
+    #[test]
+    fn test_between() {
+        let c4 = Note::new(Pitch::from(C), 4);
+        let fifth = Interval::between(&c4, &Note::new(Pitch::from(G), 4)).unwrap();
+        assert_eq!(fifth.semitone_count, 7);
+        assert_eq!(fifth.number.to_string(), "Fifth");
+        assert_eq!(fifth.quality.to_string(), "Perfect");
+
+        let tenth = Interval::between(&c4, &Note::new(Pitch::from(E), 5)).unwrap();
+        assert_eq!(tenth.semitone_count, 16);
+        assert_eq!(tenth.quality.to_string(), "Major");
+
+        let down = Interval::between(&Note::new(Pitch::from(G), 4), &c4).unwrap();
+        assert_eq!(down.semitone_count, 7);
+
+        let unison = Interval::between(&Note::new(Pitch::from(Bs), 3), &c4).unwrap();
+        assert_eq!(unison.semitone_count, 0);
+
+        let span = Interval::between(&c4, &Note::new(Pitch::from(C), 7)).unwrap();
+        assert_eq!(span.semitone_count, 36);
+    }
+
+    #[test]
+    fn test_name() {
+        let names = [
+            (0, "Unison"),
+            (1, "Minor Second"),
+            (3, "Minor Third"),
+            (4, "Major Third"),
+            (5, "Perfect Fourth"),
+            (6, "Diminished Fifth"),
+            (7, "Perfect Fifth"),
+            (11, "Major Seventh"),
+            (12, "Octave"),
+            (13, "Minor Ninth"),
+            (16, "Major Tenth"),
+            (17, "Perfect Eleventh"),
+            (19, "Perfect Twelfth"),
+            (21, "Major Thirteenth"),
+            (24, "Perfect Fifteenth"),
+        ];
+
+        for (semitones, name) in names.iter() {
+            let interval = Interval::from_semitone(*semitones).unwrap();
+            assert_eq!(interval.name(), *name);
+            assert_eq!(interval.to_string(), *name);
+        }
+    }
+
+    #[test]
+    fn test_from_notes() {
+        let table = [
+            (("C", 4), ("E", 6), 28, "Major Seventeenth"),
+            (("C", 4), ("D", 5), 14, "Major Ninth"),
+            (("C", 4), ("G", 6), 31, "Perfect Nineteenth"),
+            (("C", 4), ("C", 7), 36, "Perfect Twenty-Second"),
+            (("C", 4), ("C#", 5), 13, "Augmented Octave"),
+            (("C", 4), ("Db", 5), 13, "Minor Ninth"),
+            (("C", 4), ("Fb", 4), 4, "Diminished Fourth"),
+            (("C", 4), ("E", 4), 4, "Major Third"),
+            (("C", 4), ("B#", 4), 12, "Augmented Seventh"),
+            (("C", 4), ("Cb", 5), 11, "Diminished Octave"),
+            (("Bb", 3), ("A", 5), 23, "Major Fourteenth"),
+            (("E", 6), ("C", 4), 28, "Major Seventeenth"),
+        ];
+
+        for ((a, a_octave), (b, b_octave), semitones, name) in table {
+            let interval = Interval::from_notes(&note(a, a_octave), &note(b, b_octave)).unwrap();
+            assert_eq!(interval.semitone_count, semitones);
+            assert_eq!(interval.name(), name);
+        }
+
+        assert!(Interval::from_notes(&note("C", 4), &note("F##", 4)).is_err());
+
+        let augmented_second = Interval::from_notes(&note("F", 4), &note("G#", 4)).unwrap();
+        assert_eq!(augmented_second.step, Some(Step::AugmentedSecond));
+        let minor_third = Interval::from_notes(&note("F", 4), &note("Ab", 4)).unwrap();
+        assert_eq!(minor_third.step, None);
+    }
+
+    #[test]
+    fn test_large_compound_interval() {
+        let seventeenth = Interval::from_semitone(28).unwrap();
+        assert_eq!(seventeenth.number.to_string(), "Third");
+        assert_eq!(seventeenth.name(), "Major Seventeenth");
+        assert_eq!(Interval::from_semitone(36).unwrap().name(), "Perfect Twenty-Second");
+
+        let c4 = Note::new(Pitch::from(C), 4);
+        let e6 = Note::new(Pitch::from(E), 6);
+        assert_eq!(Interval::between(&c4, &e6).unwrap().semitone_count, 28);
+        assert_eq!(seventeenth.second_note_from(c4).to_midi(), e6.to_midi());
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::collections::HashSet;
+
+        let fifth = Interval::from_semitone(7).unwrap();
+        assert_eq!(fifth, Interval::from_semitones(&[7]).unwrap()[0]);
+        assert_ne!(fifth, Interval::from_semitone(19).unwrap());
+
+        let intervals: HashSet<Interval> = [3, 7, 3, 15]
+            .iter()
+            .map(|&semitones| Interval::from_semitone(semitones).unwrap())
+            .collect();
+        assert_eq!(intervals.len(), 3);
+    }
+
+    #[test]
+    fn test_short_name() {
+        let names = [(0, "P1"), (3, "m3"), (6, "d5"), (7, "P5"), (11, "M7"), (12, "P8"), (14, "M9")];
+        for (semitones, name) in names.iter() {
+            assert_eq!(Interval::from_semitone(*semitones).unwrap().short_name(), *name);
+        }
+
+        let c4 = Note::new(Pitch::from(C), 4);
+        let augmented_fourth = Interval::from_notes(&c4, &Note::new(Pitch::from(Fs), 4)).unwrap();
+        assert_eq!(augmented_fourth.short_name(), "A4");
+    }
+
+    #[test]
+    fn test_simple() {
+        let tenth = Interval::from_semitone(16).unwrap();
+        assert!(tenth.is_compound());
+        assert_eq!(tenth.name(), "Major Tenth");
+        assert_eq!(tenth.simple(), Interval::from_semitone(4).unwrap());
+        assert_eq!(tenth.simple().name(), "Major Third");
+        assert_eq!(Interval::from_semitone(37).unwrap().simple().short_name(), "m2");
+        assert_eq!(Interval::from_semitone(13).unwrap().simple(), Interval::from_semitone(1).unwrap());
+
+        let octave = Interval::from_semitone(12).unwrap();
+        assert!(!octave.is_compound());
+        assert_eq!(octave.simple(), octave);
+        assert_eq!(Interval::from_semitone(24).unwrap().simple(), octave);
+        assert!(!Interval::from_semitone(7).unwrap().is_compound());
+
+        let augmented_eleventh = Interval::from_notes(&note("C", 4), &note("F#", 5)).unwrap();
+        assert_eq!(augmented_eleventh.simple().name(), "Augmented Fourth");
+        assert_eq!(augmented_eleventh.simple().semitone_count, 6);
+        let augmented_octave = Interval::from_notes(&note("C", 4), &note("C#", 5)).unwrap();
+        assert!(!augmented_octave.is_compound());
+    }
+
+    #[test]
+    fn test_second_note_down_from_octave_zero() {
+        let c0 = Note::new(Pitch::from(C), 0);
+        let third = Interval::from_semitone(4).unwrap();
+        assert_eq!(third.second_note_down_from(c0.clone()).to_string(), "C0");
+        assert_eq!(third.second_note_down_from(Note::new(Pitch::from(E), 0)).to_string(), "C0");
+        assert_eq!(Interval::to_notes_reverse(c0, vec![third, third]).last().unwrap().octave, 0);
+    }
+
+    #[test]
+    fn test_display() {
+        let display = |semitones| Interval::from_semitone(semitones).unwrap().to_string();
+        assert_eq!(display(7), "Perfect Fifth");
+        assert_eq!(display(3), "Minor Third");
+        assert_eq!(display(12), "Octave");
+        assert_eq!(display(14), "Major Ninth");
+        assert_eq!(display(36), "Perfect Twenty-Second");
+        assert_eq!(display(40), "40 semitones");
+        assert_eq!(format!("{}", Interval::from_semitone(u8::MAX).unwrap()), "255 semitones");
+
+        let diminished_fourth = Interval::from_notes(&note("C", 4), &note("Fb", 4)).unwrap();
+        assert_eq!(diminished_fourth.to_string(), "Diminished Fourth");
+    }
 }
@@ -0,0 +1,225 @@
+extern crate rust_music_theory as theory;
+use theory::interval::Interval;
+use theory::note::{NoteLetter::*, *};
+
+#[cfg(test)]
+mod note_tests {
+    use super::*;
+
+    #[test]
+    fn test_sharpen() {
+        let note = Note::new(Pitch::new(C, 0), 4).sharpen();
+        assert_eq!(note.pitch, Pitch::new(C, 1));
+        assert_eq!(note.octave, 4);
+
+        let note = Note::new(Pitch::new(E, 0), 4).sharpen();
+        assert_eq!(note.pitch, Pitch::new(E, 1));
+        assert_eq!(note.pitch.into_u8(), Pitch::new(F, 0).into_u8());
+
+        let note = Note::new(Pitch::new(B, -1), 4).sharpen();
+        assert_eq!(note.pitch, Pitch::new(B, 0));
+    }
+
+    #[test]
+    fn test_flatten() {
+        let note = Note::new(Pitch::new(D, 0), 4).flatten();
+        assert_eq!(note.pitch, Pitch::new(D, -1));
+
+        let note = Note::new(Pitch::new(C, 0), 4).flatten();
+        assert_eq!(note.pitch, Pitch::new(C, -1));
+        assert_eq!(note.pitch.into_u8(), 11);
+        assert_eq!(note.octave, 4);
+    }
+
+    #[test]
+    fn test_natural() {
+        let note = Note::new(Pitch::new(F, 2), 3).natural();
+        assert_eq!(note.pitch, Pitch::new(F, 0));
+        assert_eq!(note.octave, 3);
+    }
+
+    #[test]
+    fn test_frequency_table() {
+        let table = frequency_table();
+        assert_eq!(table[69], 440.0);
+        assert!((table[60] - 261.63).abs() < 0.01);
+        assert_eq!(table[81], 880.0);
+    }
+
+    #[test]
+    fn test_frequency() {
+        assert_eq!(Note::new(Pitch::new(A, 0), 4).frequency(), 440.0);
+        assert!((Note::new(Pitch::new(C, 0), 4).frequency() - 261.63).abs() < 0.01);
+        assert_eq!(
+            Note::new(Pitch::new(G, 1), 4).frequency(),
+            Note::new(Pitch::new(A, -1), 4).frequency()
+        );
+        assert_eq!(Note::new(Pitch::new(C, -1), 0).frequency(), frequency_table()[11]);
+        assert!(Note::new(Pitch::new(C, 0), 12).frequency() > frequency_table()[127]);
+    }
+
+    #[test]
+    fn test_to_frequency() {
+        assert_eq!(Note::new(Pitch::new(A, 0), 4).to_frequency(), 440.0);
+        assert!((Note::new(Pitch::new(C, 0), 4).to_frequency() - 261.63).abs() < 0.01);
+        assert_eq!(Note::new(Pitch::new(A, 0), 4).to_frequency_with_reference(432.0), 432.0);
+        assert_eq!(Note::new(Pitch::new(A, 0), 5).to_frequency_with_reference(432.0), 864.0);
+
+        let lowest = Note::new(Pitch::new(C, -1), 0).to_frequency();
+        assert!(lowest > 0.0 && lowest < 16.0);
+        assert!(Note::new(Pitch::new(B, 2), 255).to_frequency().is_finite());
+
+        for midi in 12..=127 {
+            let note = Note::from_midi(midi).unwrap();
+            assert_eq!(note.to_frequency(), note.frequency());
+        }
+    }
+
+    #[test]
+    fn test_midi() {
+        assert_eq!(Note::new(Pitch::new(C, 0), 4).to_midi(), 60);
+        assert_eq!(Note::new(Pitch::new(A, 0), 4).to_midi(), 69);
+        assert_eq!(Note::new(Pitch::new(B, 1), 3).to_midi(), 60);
+        assert_eq!(Note::new(Pitch::new(C, 0), 0).to_midi(), 12);
+        assert_eq!(Note::new(Pitch::new(C, 0), 20).to_midi(), 127);
+
+        let note = Note::from_midi(61).unwrap();
+        assert_eq!((note.pitch, note.octave), (Pitch::new(C, 1), 4));
+
+        for midi in [12u8, 35, 60, 69, 100, 127].iter() {
+            assert_eq!(Note::from_midi(*midi).unwrap().to_midi(), *midi);
+        }
+
+        assert!(matches!(Note::from_midi(0), Err(NoteError::InvalidMidi(0))));
+        assert!(matches!(Note::from_midi(11), Err(NoteError::InvalidMidi(11))));
+        assert_eq!(Note::from_midi(12).unwrap(), Note::new(Pitch::new(C, 0), 0));
+        assert_eq!(Note::new(Pitch::new(C, -1), 0).to_midi(), 11);
+        assert!(matches!(Note::from_midi(128), Err(NoteError::InvalidMidi(128))));
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut notes = [
+            Note::new(Pitch::new(G, 0), 4),
+            Note::new(Pitch::new(C, 0), 5),
+            Note::new(Pitch::new(A, 0), 3),
+            Note::new(Pitch::new(E, -1), 4),
+            Note::new(Pitch::new(C, 0), 4),
+            Note::new(Pitch::new(B, 0), 2),
+        ];
+        notes.sort();
+
+        let sorted: Vec<(Pitch, u8)> = notes.iter().map(|note| (note.pitch, note.octave)).collect();
+        assert_eq!(
+            sorted,
+            vec![
+                (Pitch::new(B, 0), 2),
+                (Pitch::new(A, 0), 3),
+                (Pitch::new(C, 0), 4),
+                (Pitch::new(E, -1), 4),
+                (Pitch::new(G, 0), 4),
+                (Pitch::new(C, 0), 5),
+            ]
+        );
+
+        // enharmonic notes are ordered by accidental but are not equal
+        let b_sharp = Note::new(Pitch::new(B, 1), 3);
+        let c = Note::new(Pitch::new(C, 0), 4);
+        assert!(c < b_sharp);
+        assert!(b_sharp < Note::new(Pitch::new(C, 1), 4));
+        assert_ne!(c, b_sharp);
+        assert_eq!(c, Note::new(Pitch::new(C, 0), 4));
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::collections::HashSet;
+
+        let notes: HashSet<Note> = [
+            Note::new(Pitch::new(C, 0), 4),
+            Note::new(Pitch::new(E, 0), 4),
+            Note::new(Pitch::new(C, 0), 4),
+            Note::new(Pitch::new(C, 0), 5),
+            Note::new(Pitch::new(B, 1), 3),
+        ]
+        .iter()
+        .cloned()
+        .collect();
+        assert_eq!(notes.len(), 4);
+        assert!(notes.contains(&Note::new(Pitch::new(C, 0), 5)));
+        assert!(!notes.contains(&Note::new(Pitch::new(E, 0), 5)));
+
+        let pitches: HashSet<Pitch> = [Pitch::new(C, 1), Pitch::new(D, -1), Pitch::new(C, 1)]
+            .iter()
+            .copied()
+            .collect();
+        assert_eq!(pitches.len(), 2);
+    }
+
+    #[test]
+    fn test_interval_arithmetic() {
+        let fifth = Interval::from_semitone(7).unwrap();
+        let minor_third = Interval::from_semitone(3).unwrap();
+        let major_second = Interval::from_semitone(2).unwrap();
+        let note = |letter, accidental, octave| Note::new(Pitch::new(letter, accidental), octave);
+
+        assert_eq!(note(C, 0, 4) + fifth, note(G, 0, 4));
+        assert_eq!(note(A, 0, 4) + minor_third, note(C, 0, 5));
+        assert_eq!(note(C, 0, 4) - major_second, note(B, -1, 3));
+        assert_eq!(note(C, 0, 4) + minor_third, note(E, -1, 4));
+        assert_eq!(note(B, 0, 4) + major_second, note(C, 1, 5));
+        assert_eq!(note(E, 0, 4) + Interval::from_semitone(16).unwrap(), note(G, 1, 5));
+        assert_eq!(note(D, 0, 0) - fifth, note(C, 0, 0));
+    }
+
+    #[test]
+    fn test_tuning() {
+        let c4 = Note::new(Pitch::new(C, 0), 4);
+        let e4 = Note::new(Pitch::new(E, 0), 4);
+        let g4 = Note::new(Pitch::new(G, 0), 4);
+        let c_major = Pitch::new(C, 0);
+
+        let equal = Tuning::EqualTemperament;
+        assert_eq!(equal.frequency(&e4), e4.to_frequency());
+        assert_eq!(Tuning::default(), equal);
+
+        let just = Tuning::JustIntonation { key: c_major };
+        assert_eq!(just.frequency(&c4), c4.to_frequency());
+        assert!((just.frequency(&e4) / just.frequency(&c4) - 1.25).abs() < 1e-9);
+        assert!((equal.frequency(&e4) / equal.frequency(&c4) - 1.25).abs() > 0.009);
+        assert!((just.frequency(&g4) / just.frequency(&c4) - 1.5).abs() < 1e-9);
+        let e5 = Note::new(Pitch::new(E, 0), 5);
+        assert!((just.frequency(&e5) / just.frequency(&e4) - 2.0).abs() < 1e-9);
+        let b3 = Note::new(Pitch::new(B, 0), 3);
+        assert!((just.frequency(&c4) / just.frequency(&b3) - 16.0 / 15.0).abs() < 1e-9);
+
+        let pythagorean = Tuning::Pythagorean { key: c_major };
+        assert!((pythagorean.frequency(&e4) / pythagorean.frequency(&c4) - 81.0 / 64.0).abs() < 1e-9);
+
+        let a_major = Tuning::JustIntonation { key: Pitch::new(A, 0) };
+        let a4 = Note::new(Pitch::new(A, 0), 4);
+        assert_eq!(a_major.frequency(&a4), 440.0);
+        assert!((a_major.frequency(&Note::new(Pitch::new(C, 1), 5)) - 550.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_display_and_style() {
+        let c_sharp = Note::new(Pitch::new(C, 1), 4);
+        let d_flat = Note::new(Pitch::new(D, -1), 4);
+        assert_eq!(c_sharp.to_string(), "C#4");
+        assert_eq!(d_flat.to_string(), "Db4");
+
+        assert_eq!(c_sharp.name_with_style(AccidentalStyle::Sharp), "C#4");
+        assert_eq!(c_sharp.name_with_style(AccidentalStyle::Flat), "Db4");
+        assert_eq!(d_flat.name_with_style(AccidentalStyle::Sharp), "C#4");
+        assert_eq!(d_flat.name_with_style(AccidentalStyle::Flat), "Db4");
+
+        assert_eq!(d_flat.with_style(AccidentalStyle::Sharp).pitch.to_string(), "C#");
+        assert_eq!(c_sharp.pitch.with_style(AccidentalStyle::Flat).to_string(), "Db");
+
+        let b_sharp = Note::new(Pitch::new(B, 1), 3);
+        assert_eq!(b_sharp.name_with_style(AccidentalStyle::Sharp), "C4");
+        let c_flat = Note::new(Pitch::new(C, -1), 4);
+        assert_eq!(c_flat.name_with_style(AccidentalStyle::Flat), "B3");
+    }
+}
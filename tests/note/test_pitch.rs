@@ -44,7 +44,7 @@ mod test_note {
 
     #[test]
     fn test_pitch_from_str_err() {
-        for string in vec!["Ca", "Q", "Cb#", "B♯b#"] {
+        for string in ["Ca", "Q", "Cb#", "B♯b#"] {
             assert!(Pitch::from_str(string).is_none());
         }
     }
@@ -78,4 +78,78 @@ mod test_note {
         assert_eq!(format!("{}", Pitch::new(C,-2)), "Cbb");
         assert_eq!(format!("{}", Pitch::new(C,0)), "C");
     }
+
+    #[test]
+    fn test_enharmonic_equivalents() {
+        let c_sharp = Pitch::new(C, 1);
+        let d_flat = Pitch::new(D, -1);
+        assert_eq!(c_sharp.into_u8(), d_flat.into_u8());
+        assert_eq!(c_sharp.enharmonic_equivalents(), vec!["C#", "Db", "B##"]);
+        assert_eq!(d_flat.enharmonic_equivalents(), c_sharp.enharmonic_equivalents());
+
+        assert_eq!(Pitch::new(C, 0).enharmonic_equivalents(), vec!["C", "B#", "Dbb"]);
+        assert_eq!(Pitch::new(G, 1).enharmonic_equivalents(), vec!["G#", "Ab"]);
+        assert_eq!(Pitch::new(E, 0).enharmonic_equivalents(), vec!["E", "Fb", "D##"]);
+    }
+
+    #[test]
+    fn test_double_accidentals() {
+        let table = vec![
+            ("C##", Pitch::new(C, 2), 2),
+            ("Cx", Pitch::new(C, 2), 2),
+            ("Dbb", Pitch::new(D, -2), 0),
+            ("Fbb", Pitch::new(F, -2), 3),
+            ("B𝄫", Pitch::new(B, -2), 9),
+            ("E𝄪", Pitch::new(E, 2), 6),
+        ];
+
+        for (string, pitch, value) in table {
+            let (parsed, pitch_match) = Pitch::from_regex(string).unwrap();
+            assert_eq!(parsed, pitch);
+            assert_eq!(parsed.into_u8(), value);
+            assert_eq!(pitch_match.end(), string.len());
+        }
+
+        let (pitch, pitch_match) = Pitch::from_regex("G## dominant seventh").unwrap();
+        assert_eq!(pitch, Pitch::new(G, 2));
+        assert_eq!(pitch_match.as_str(), "G##");
+    }
+
+    #[test]
+    fn test_regex_stops_at_mixed_accidentals() {
+        let (pitch, pitch_match) = Pitch::from_regex("Dbsus4").unwrap();
+        assert_eq!((pitch, pitch_match.as_str()), (Pitch::new(D, -1), "Db"));
+        let (pitch, pitch_match) = Pitch::from_regex("C#b").unwrap();
+        assert_eq!((pitch, pitch_match.as_str()), (Pitch::new(C, 1), "C#"));
+    }
+
+    #[test]
+    fn test_from_midi_and_arithmetic() {
+        assert_eq!(Pitch::from_midi(60), Pitch::new(C, 0));
+        assert_eq!(Pitch::from_midi(72), Pitch::new(C, 0));
+        assert_eq!(Pitch::from_midi(61), Pitch::new(C, 1));
+        assert_eq!(Pitch::from_midi(0), Pitch::new(C, 0));
+        assert_eq!(Pitch::from_midi(127), Pitch::new(G, 0));
+
+        let c = Pitch::new(C, 0);
+        assert_eq!(c + 7, Pitch::new(G, 0));
+        assert_eq!(c - 1, Pitch::new(B, 0));
+        assert_eq!(c + 12, c);
+        assert_eq!(c + -5, Pitch::new(G, 0));
+        assert_eq!(c - -1, Pitch::new(C, 1));
+        assert_eq!(c - i8::MIN, c + 8);
+        assert_eq!(Pitch::new(B, 0) + 1, c);
+        assert_eq!(Pitch::new(D, -1) + 2, Pitch::new(E, -1));
+        assert_eq!(Pitch::new(A, 0) - 3, Pitch::new(F, 1));
+    }
+
+    #[test]
+    fn test_try_from() {
+        use std::convert::TryFrom;
+        use theory::note::NoteError;
+
+        assert_eq!(Pitch::try_from("C#").unwrap(), Pitch::new(C, 1));
+        assert_eq!(Pitch::try_from("Bb").unwrap(), Pitch::new(B, -1));
+        assert!(matches!(Pitch::try_from("H"), Err(NoteError::InvalidPitch)));
+    }
 }
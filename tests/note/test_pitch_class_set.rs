@@ -0,0 +1,43 @@
+extern crate rust_music_theory as theory;
+use theory::note::{PitchClassSet, PitchSymbol::*, *};
+
+#[cfg(test)]
+mod pitch_class_set_tests {
+    use super::*;
+
+    fn set(symbols: &[PitchSymbol]) -> PitchClassSet {
+        let pitches: Vec<Pitch> = symbols.iter().map(|&symbol| Pitch::from(symbol)).collect();
+        PitchClassSet::new(&pitches)
+    }
+
+    #[test]
+    fn test_major_triad() {
+        let triad = set(&[G, C, E, C]);
+        assert_eq!(triad.classes(), [0, 4, 7]);
+        assert_eq!(triad.normal_form(), [0, 4, 7]);
+        assert_eq!(triad.prime_form(), [0, 3, 7]);
+        assert_eq!(triad.interval_vector(), [0, 0, 1, 1, 1, 0]);
+
+        let minor = set(&[A, C, E]);
+        assert_eq!(minor.normal_form(), [9, 0, 4]);
+        assert_eq!(minor.prime_form(), triad.prime_form());
+    }
+
+    #[test]
+    fn test_diminished_seventh() {
+        let diminished = set(&[B, D, F, Ab]);
+        assert_eq!(diminished.normal_form(), [2, 5, 8, 11]);
+        assert_eq!(diminished.prime_form(), [0, 3, 6, 9]);
+        assert_eq!(diminished.interval_vector(), [0, 0, 4, 0, 0, 2]);
+        assert_eq!(diminished, set(&[Cb, D, F, Gs]));
+    }
+
+    #[test]
+    fn test_dominant_seventh() {
+        let dominant = PitchClassSet::from_classes(vec![0, 4, 7, 10]);
+        assert_eq!(dominant.normal_form(), [4, 7, 10, 0]);
+        assert_eq!(dominant.prime_form(), [0, 2, 5, 8]);
+        assert_eq!(dominant.interval_vector(), [0, 1, 2, 1, 1, 1]);
+        assert!(PitchClassSet::from_classes(vec![]).prime_form().is_empty());
+    }
+}
@@ -0,0 +1,60 @@
+extern crate rust_music_theory as theory;
+use theory::note::{NoteLetter, Pitch};
+use theory::scale::{KeySignature, Scale};
+
+#[cfg(test)]
+mod key_signature_tests {
+    use super::*;
+
+    fn signature(name: &str) -> Option<KeySignature> {
+        KeySignature::from_scale(&Scale::from_regex(name).unwrap())
+    }
+
+    fn accidentals(name: &str) -> Vec<String> {
+        let signature = signature(name).unwrap();
+        signature.accidentals().iter().map(Pitch::to_string).collect()
+    }
+
+    #[test]
+    fn test_major_keys() {
+        assert_eq!(accidentals("C major"), Vec::<String>::new());
+        assert_eq!(accidentals("G major"), vec!["F#"]);
+        assert_eq!(accidentals("Eb major"), vec!["Bb", "Eb", "Ab"]);
+        assert_eq!(accidentals("C# major"), vec!["F#", "C#", "G#", "D#", "A#", "E#", "B#"]);
+        assert_eq!(accidentals("Cb major"), vec!["Bb", "Eb", "Ab", "Db", "Gb", "Cb", "Fb"]);
+        assert_eq!(accidentals("G# major"), vec!["F#", "C#", "G#", "D#", "A#", "E#", "B#", "F##"]);
+
+        let g = signature("G major").unwrap();
+        assert_eq!((g.fifths, g.sharps(), g.flats()), (1, 1, 0));
+        let eb = signature("Eb major").unwrap();
+        assert_eq!((eb.fifths, eb.sharps(), eb.flats()), (-3, 0, 3));
+    }
+
+    #[test]
+    fn test_minor_keys() {
+        assert_eq!(accidentals("A minor"), Vec::<String>::new());
+        assert_eq!(accidentals("E minor"), vec!["F#"]);
+        assert_eq!(accidentals("C minor"), vec!["Bb", "Eb", "Ab"]);
+        assert_eq!(accidentals("D# minor"), vec!["F#", "C#", "G#", "D#", "A#", "E#"]);
+        assert_eq!(signature("C harmonic minor"), signature("C minor"));
+        assert_eq!(signature("F# melodic minor"), signature("F# minor"));
+        assert_eq!(signature("D dorian"), signature("C major"));
+    }
+
+    #[test]
+    fn test_accidental() {
+        let d = signature("D major").unwrap();
+        assert_eq!(d.accidental(NoteLetter::F), 1);
+        assert_eq!(d.accidental(NoteLetter::C), 1);
+        assert_eq!(d.accidental(NoteLetter::G), 0);
+        assert_eq!(KeySignature::new(-2).accidental(NoteLetter::E), -1);
+        assert_eq!(KeySignature::new(8).accidental(NoteLetter::F), 2);
+    }
+
+    #[test]
+    fn test_unsupported_scales() {
+        assert_eq!(signature("C blues"), None);
+        assert_eq!(signature("C chromatic"), None);
+        assert_eq!(signature("C whole tone"), None);
+    }
+}
@@ -1,6 +1,7 @@
 extern crate rust_music_theory as theory;
-use theory::note::{NoteLetter::*, Pitch};
-use theory::scale::{Mode, Scale, ScaleType};
+use theory::note::{NoteLetter::*, Notes, Pitch};
+use theory::scale::{Direction, Mode, Scale, ScaleError, ScaleType};
+use strum::IntoEnumIterator;
 
 #[cfg(test)]
 mod chord_regex_tests {
@@ -32,6 +33,33 @@ mod chord_regex_tests {
                 ScaleType::MelodicMinor,
                 Mode::MelodicMinor,
             ),
+            ("C whole tone", Pitch::new(C, 0), ScaleType::WholeTone, Mode::WholeTone),
+            ("D chromatic", Pitch::new(D, 0), ScaleType::Chromatic, Mode::Chromatic),
+            (
+                "A minor pentatonic",
+                Pitch::new(A, 0),
+                ScaleType::MinorPentatonic,
+                Mode::MinorPentatonic,
+            ),
+            (
+                "G Major Pentatonic",
+                Pitch::new(G, 0),
+                ScaleType::MajorPentatonic,
+                Mode::MajorPentatonic,
+            ),
+            ("E blues", Pitch::new(E, 0), ScaleType::Blues, Mode::Blues),
+            (
+                "G bebop dominant",
+                Pitch::new(G, 0),
+                ScaleType::BebopDominant,
+                Mode::BebopDominant,
+            ),
+            (
+                "C harmonic major",
+                Pitch::new(C, 0),
+                ScaleType::HarmonicMajor,
+                Mode::HarmonicMajor,
+            ),
         ];
 
         for (string, pitch, scale_type, mode) in table {
@@ -41,4 +69,127 @@ mod chord_regex_tests {
             assert_eq!(scale.scale_type, scale_type);
         }
     }
+
+    #[test]
+    fn test_octave() {
+        let scale = Scale::from_regex("C5 major").unwrap();
+        assert_eq!(scale.octave, 5);
+        let first = &scale.notes()[0];
+        assert_eq!((first.pitch, first.octave), (Pitch::new(C, 0), 5));
+
+        let scale = Scale::from_regex("C major").unwrap();
+        assert_eq!(scale.octave, Scale::DEFAULT_OCTAVE);
+        let first = &scale.notes()[0];
+        assert_eq!((first.pitch, first.octave), (Pitch::new(C, 0), 4));
+
+        let notes = Scale::from_regex("C3 major").unwrap().notes();
+        assert_eq!((notes[0].pitch, notes[0].octave), (Pitch::new(C, 0), 3));
+        assert_eq!((notes[7].pitch, notes[7].octave), (Pitch::new(C, 0), 4));
+
+        let scale = Scale::from_regex("Eb3 dorian").unwrap();
+        assert_eq!((scale.tonic, scale.octave), (Pitch::new(E, -1), 3));
+    }
+
+    #[test]
+    fn test_mode_longest_match() {
+        let scale = Scale::from_regex("C harmonic minor").unwrap();
+        assert_eq!(scale.mode, Some(Mode::HarmonicMinor));
+        assert_eq!(scale.scale_type, ScaleType::HarmonicMinor);
+
+        let scale = Scale::from_regex("C melodic minor").unwrap();
+        assert_eq!(scale.mode, Some(Mode::MelodicMinor));
+        assert_eq!(scale.scale_type, ScaleType::MelodicMinor);
+
+        let (mode, mode_match) = Mode::from_regex("m pentatonic").unwrap();
+        assert_eq!((mode, mode_match.as_str()), (Mode::MinorPentatonic, "m pentatonic"));
+
+        assert!(matches!(Mode::from_regex("blah minor"), Err(ScaleError::ModeFromRegex)));
+        assert!(matches!(Scale::from_regex("C nonsense"), Err(ScaleError::ModeFromRegex)));
+    }
+
+    #[test]
+    fn test_parse() {
+        let scale: Scale = "A dorian".parse().unwrap();
+        assert_eq!((scale.tonic, scale.mode), (Pitch::new(A, 0), Some(Mode::Dorian)));
+        assert_eq!(scale.scale_type, ScaleType::Diatonic);
+        assert!(matches!("A nonsense".parse::<Scale>(), Err(ScaleError::ModeFromRegex)));
+    }
+
+    #[test]
+    fn test_alterations() {
+        let names = |scale: &Scale| scale.notes().iter().map(|note| note.pitch.to_string()).collect::<Vec<_>>();
+
+        let scale = Scale::from_regex("C major #4").unwrap();
+        assert_eq!(scale.scale_type, ScaleType::Custom);
+        assert_eq!(scale.note_on_degree(4).unwrap().pitch, Pitch::new(F, 1));
+        assert_eq!(names(&scale), vec!["C", "D", "E", "F#", "G", "A", "B", "C"]);
+        assert_eq!(names(&Scale::from_regex("C minor b5").unwrap()), vec!["C", "D", "Eb", "F", "Gb", "Ab", "Bb", "C"]);
+        assert_eq!(names(&Scale::from_regex("D dorian ♭2 ♯4").unwrap()), vec!["D", "Eb", "F", "G#", "A", "B", "C", "D"]);
+
+        assert!(matches!(Scale::from_regex("C major #8"), Err(ScaleError::InvalidDegree(8))));
+        assert!(matches!(Scale::from_regex("C major b1"), Err(ScaleError::InvalidDegree(1))));
+        assert!(matches!(Scale::from_regex("C major #3"), Err(ScaleError::InvalidInterval)));
+        assert!(matches!(Scale::from_regex("C major 4"), Err(ScaleError::InvalidRegex)));
+    }
+
+    #[test]
+    fn test_display() {
+        let display = |name: &str| name.parse::<Scale>().unwrap().to_string();
+        assert_eq!(display("A harmonic minor"), "A Harmonic Minor");
+        assert_eq!(display("C major"), "C Major");
+        assert_eq!(display("C ionian"), "C Major");
+        assert_eq!(display("F# aeolian"), "F# Minor");
+        assert_eq!(display("Bb mixolydian"), "Bb Mixolydian");
+        assert_eq!(display("C major #4"), "C Custom");
+        assert!("C Custom".parse::<Scale>().is_err());
+        let custom = Scale::from_intervals(Pitch::new(C, 0), 4, &[3, 1, 3, 1, 3, 1]).unwrap();
+        assert!(custom.to_string().parse::<Scale>().is_err());
+
+        for mode in Mode::iter() {
+            let scale = Scale::new(ScaleType::from_mode(mode), Pitch::new(E, -1), 4, Some(mode), Direction::Ascending).unwrap();
+            let parsed: Scale = scale.to_string().parse().unwrap();
+            assert_eq!(parsed.to_string(), scale.to_string());
+            assert_eq!(parsed.notes(), scale.notes(), "{}", scale);
+        }
+    }
+
+    #[test]
+    fn test_flat_tonics() {
+        let names = |scale: &Scale| scale.notes().iter().map(|note| note.pitch.to_string()).collect::<Vec<_>>().join(" ");
+        let table = [
+            ("Bb dorian", Pitch::new(B, -1), Mode::Dorian, "Bb C Db Eb F G Ab Bb"),
+            ("Eb major", Pitch::new(E, -1), Mode::Ionian, "Eb F G Ab Bb C D Eb"),
+            ("Db melodic minor", Pitch::new(D, -1), Mode::MelodicMinor, "Db Eb Fb Gb Ab Bb C Db"),
+            ("Ab minor", Pitch::new(A, -1), Mode::Aeolian, "Ab Bb Cb Db Eb Fb Gb Ab"),
+            ("E♭ lydian", Pitch::new(E, -1), Mode::Lydian, "Eb F G A Bb C D Eb"),
+            ("Gbm", Pitch::new(G, -1), Mode::Aeolian, "Gb Ab Bbb Cb Db Ebb Fb Gb"),
+            ("  Gb  lydian ", Pitch::new(G, -1), Mode::Lydian, "Gb Ab Bb C Db Eb F Gb"),
+            ("Db minor pentatonic", Pitch::new(D, -1), Mode::MinorPentatonic, "Db Fb Gb Ab Cb Db"),
+            ("C blues", Pitch::new(C, 0), Mode::Blues, "C Eb F Gb G Bb C"),
+            ("F# blues", Pitch::new(F, 1), Mode::Blues, "F# A B C C# E F#"),
+            ("Bb bebop dominant", Pitch::new(B, -1), Mode::BebopDominant, "Bb C D Eb F G Ab A Bb"),
+            ("Eb blues", Pitch::new(E, -1), Mode::Blues, "Eb Gb Ab A Bb Db Eb"),
+        ];
+
+        for (string, tonic, mode, notes) in table.iter() {
+            let scale = Scale::from_regex(string).unwrap();
+            assert_eq!((scale.tonic, scale.mode), (*tonic, Some(*mode)), "{}", string);
+            assert_eq!(names(&scale), *notes, "{}", string);
+        }
+
+        assert_eq!(Scale::from_regex("Bb5 dorian").unwrap().octave, 5);
+    }
+
+    #[test]
+    fn test_try_from() {
+        use std::convert::TryFrom;
+
+        let scale = Scale::try_from("A dorian").unwrap();
+        assert_eq!((scale.tonic, scale.mode), (Pitch::new(A, 0), Some(Mode::Dorian)));
+        assert!(matches!(Scale::try_from("A nonsense"), Err(ScaleError::ModeFromRegex)));
+
+        assert_eq!(Mode::try_from("harmonic minor").unwrap(), Mode::HarmonicMinor);
+        assert_eq!(Mode::try_from("Lydian").unwrap(), Mode::Lydian);
+        assert!(matches!(Mode::try_from("dorian scale"), Err(ScaleError::ModeFromRegex)));
+    }
 }
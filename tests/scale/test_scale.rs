@@ -16,19 +16,19 @@ mod scale_tests {
     fn test_all_scales_in_c() {
         let scale_tuples = [
             ((Diatonic, Some(Ionian)), vec![C, D, E, F, G, A, B, C]),
-            ((Diatonic, Some(Dorian)), vec![C, D, Ds, F, G, A, As, C]),
-            ((Diatonic, Some(Phrygian)), vec![C, Cs, Ds, F, G, Gs, As, C]),
+            ((Diatonic, Some(Dorian)), vec![C, D, Eb, F, G, A, Bb, C]),
+            ((Diatonic, Some(Phrygian)), vec![C, Db, Eb, F, G, Ab, Bb, C]),
             ((Diatonic, Some(Lydian)), vec![C, D, E, Fs, G, A, B, C]),
-            ((Diatonic, Some(Mixolydian)), vec![C, D, E, F, G, A, As, C]),
-            ((Diatonic, Some(Aeolian)), vec![C, D, Ds, F, G, Gs, As, C]),
-            ((Diatonic, Some(Locrian)), vec![C, Cs, Ds, F, Fs, Gs, As, C]),
+            ((Diatonic, Some(Mixolydian)), vec![C, D, E, F, G, A, Bb, C]),
+            ((Diatonic, Some(Aeolian)), vec![C, D, Eb, F, G, Ab, Bb, C]),
+            ((Diatonic, Some(Locrian)), vec![C, Db, Eb, F, Gb, Ab, Bb, C]),
             (
                 (ScaleType::HarmonicMinor, None),
-                vec![C, D, Ds, F, G, Gs, B, C],
+                vec![C, D, Eb, F, G, Ab, B, C],
             ),
             (
                 (ScaleType::MelodicMinor, None),
-                vec![C, D, Ds, F, G, A, B, C],
+                vec![C, D, Eb, F, G, A, B, C],
             )
         ];
 
@@ -67,4 +67,543 @@ mod scale_tests {
             assert_eq!(note.octave, if i <= 2 { 5 } else { 6 });
         }
     }
+
+    #[test]
+    fn test_chromatic_positions() {
+        let table = [
+            ((Diatonic, Some(Ionian)), vec![0, 2, 4, 5, 7, 9, 11]),
+            ((Diatonic, Some(Dorian)), vec![0, 2, 3, 5, 7, 9, 10]),
+            ((Diatonic, Some(Locrian)), vec![0, 1, 3, 5, 6, 8, 10]),
+            ((ScaleType::HarmonicMinor, None), vec![0, 2, 3, 5, 7, 8, 11]),
+        ];
+
+        for ((scale_type, mode), positions) in table.iter() {
+            let scale =
+                Scale::new(*scale_type, Pitch::from(D), 4, *mode, Direction::Descending).unwrap();
+            assert_eq!(&scale.chromatic_positions(), positions);
+        }
+    }
+
+    #[test]
+    fn test_letter_spelling() {
+        let scale = Scale::new(Diatonic, Pitch::from(Gb), 4, Some(Ionian), Direction::Ascending).unwrap();
+        assert_notes(&[Gb, Ab, Bb, Cb, Db, Eb, F, Gb], scale.notes());
+
+        let octaves: Vec<u8> = scale.notes().iter().map(|note| note.octave).collect();
+        assert_eq!(octaves, vec![4, 4, 4, 5, 5, 5, 5, 5]);
+
+        let scale = Scale::new(Diatonic, Pitch::from(Cs), 4, Some(Ionian), Direction::Descending).unwrap();
+        assert_notes(&[Cs, Bs, As, Gs, Fs, Es, Ds, Cs], scale.notes());
+    }
+
+    #[test]
+    fn test_contains_all() {
+        let scale = Scale::new(Diatonic, Pitch::from(G), 4, Some(Ionian), Direction::Ascending).unwrap();
+        let phrase: Vec<Pitch> = [G, A, B, D, Fs, E].iter().map(|&s| Pitch::from(s)).collect();
+        assert!(scale.contains_all(&phrase));
+        assert!(scale.out_of_key(&phrase).is_empty());
+        assert!(scale.contains_all(&[Pitch::from(Gb)]));
+
+        let chromatic: Vec<Pitch> = [G, Gs, A, Bb, B, F].iter().map(|&s| Pitch::from(s)).collect();
+        assert!(!scale.contains_all(&chromatic));
+        assert_eq!(
+            scale.out_of_key(&chromatic),
+            vec![Pitch::from(Gs), Pitch::from(Bb), Pitch::from(F)]
+        );
+
+        let scale = Scale::new(Diatonic, Pitch::from(C), 4, Some(Dorian), Direction::Ascending).unwrap();
+        assert!(scale.contains_all(&[Pitch::from(Eb), Pitch::from(Ds), Pitch::from(A)]));
+        assert_eq!(scale.out_of_key(&[Pitch::from(E), Pitch::from(B)]).len(), 2);
+    }
+
+    #[test]
+    fn test_diatonic_chords() {
+        let symbols = |chords: Vec<theory::chord::Chord>| -> Vec<String> {
+            chords.iter().map(|chord| chord.to_string()).collect()
+        };
+
+        let c_major = Scale::new(Diatonic, Pitch::from(C), 4, Some(Ionian), Direction::Ascending).unwrap();
+        assert_eq!(symbols(c_major.diatonic_chords()), ["C", "Dm", "Em", "F", "G", "Am", "Bdim"]);
+        assert_eq!(
+            symbols(c_major.diatonic_sevenths()),
+            ["Cmaj7", "Dm7", "Em7", "Fmaj7", "G7", "Am7", "Bø7"]
+        );
+
+        let a_minor = Scale::new(Diatonic, Pitch::from(A), 4, Some(Aeolian), Direction::Ascending).unwrap();
+        assert_eq!(symbols(a_minor.diatonic_chords()), ["Am", "Bdim", "C", "Dm", "Em", "F", "G"]);
+        assert_eq!(
+            symbols(a_minor.diatonic_sevenths()),
+            ["Am7", "Bø7", "Cmaj7", "Dm7", "Em7", "Fmaj7", "G7"]
+        );
+
+        let eb_major = Scale::new(Diatonic, Pitch::from(Eb), 4, Some(Ionian), Direction::Ascending).unwrap();
+        assert_eq!(symbols(eb_major.diatonic_chords()), ["Eb", "Fm", "Gm", "Ab", "Bb", "Cm", "Ddim"]);
+    }
+
+    #[test]
+    fn test_chord_on_degree() {
+        use theory::chord::Number;
+        let c_major = Scale::new(Diatonic, Pitch::from(C), 4, Some(Ionian), Direction::Ascending).unwrap();
+        let g_major = Scale::new(Diatonic, Pitch::from(G), 4, Some(Ionian), Direction::Ascending).unwrap();
+        let cases = [
+            (&c_major, 5, Number::Seventh, "G7"),
+            (&c_major, 1, Number::Triad, "C"),
+            (&c_major, 2, Number::Triad, "Dm"),
+            (&c_major, 7, Number::Triad, "Bdim"),
+            (&c_major, 7, Number::Seventh, "Bø7"),
+            (&c_major, 4, Number::Seventh, "Fmaj7"),
+            (&g_major, 5, Number::Seventh, "D7"),
+            (&g_major, 7, Number::Triad, "F#dim"),
+            (&g_major, 1, Number::Ninth, "Gmaj9"),
+        ];
+
+        for (scale, degree, number, symbol) in cases.iter() {
+            let chord = scale.chord_on_degree(*degree, *number).unwrap();
+            assert_eq!(chord.to_string(), *symbol);
+        }
+
+        let chord = c_major.chord_on_degree(6, Number::Triad).unwrap();
+        assert_eq!((chord.root, chord.octave), (Pitch::from(A), 4));
+
+        assert!(matches!(c_major.chord_on_degree(0, Number::Triad), Err(ScaleError::InvalidDegree(0))));
+        assert!(matches!(c_major.chord_on_degree(8, Number::Triad), Err(ScaleError::InvalidDegree(8))));
+        assert!(matches!(c_major.chord_on_degree(2, Number::Ninth), Err(ScaleError::InvalidChord)));
+    }
+
+    #[test]
+    fn test_non_diatonic_scales() {
+        let scale_tuples = [
+            (ScaleType::WholeTone, 6, vec![C, D, E, Fs, Gs, As, C]),
+            (ScaleType::Chromatic, 12, vec![C, Cs, D, Ds, E, F, Fs, G, Gs, A, As, B, C]),
+            (ScaleType::MajorPentatonic, 5, vec![C, D, E, G, A, C]),
+            (ScaleType::MinorPentatonic, 5, vec![C, Eb, F, G, Bb, C]),
+            (ScaleType::Blues, 6, vec![C, Eb, F, Gb, G, Bb, C]),
+        ];
+
+        for (scale_type, count, pitches) in scale_tuples.iter() {
+            let scale = Scale::new(*scale_type, Pitch::from(C), 4, None, Direction::Ascending).unwrap();
+            assert_eq!(scale.chromatic_positions().len(), *count);
+            assert_eq!(scale.notes().len(), count + 1);
+            assert_notes(pitches, scale.notes());
+        }
+
+        let scale = Scale::from_regex("A minor pentatonic").unwrap();
+        assert_notes(&[A, C, D, E, G, A], scale.notes());
+        assert!(!Mode::Blues.is_diatonic());
+    }
+
+    #[test]
+    fn test_contains() {
+        let scale = Scale::new(Diatonic, Pitch::from(C), 4, Some(Ionian), Direction::Ascending).unwrap();
+        assert!(scale.contains(Pitch::from(E)));
+        assert!(!scale.contains(Pitch::from(Fs)));
+        assert!(scale.contains(Pitch::new(NoteLetter::F, -1)));
+        assert_eq!(scale.degree_of(Pitch::from(E)), Some(3));
+        assert_eq!(scale.degree_of(Pitch::from(C)), Some(1));
+        assert_eq!(scale.degree_of(Pitch::from(B)), Some(7));
+        assert_eq!(scale.degree_of(Pitch::from(Fs)), None);
+
+        let scale = Scale::new(Diatonic, Pitch::from(Eb), 4, Some(Aeolian), Direction::Ascending).unwrap();
+        assert_eq!(scale.degree_of(Pitch::from(Fs)), Some(3));
+        assert_eq!(scale.degree_of(Pitch::from(Gb)), Some(3));
+    }
+
+    #[test]
+    fn test_transpose() {
+        let scale = Scale::new(Diatonic, Pitch::from(C), 4, Some(Ionian), Direction::Ascending).unwrap();
+        let up = scale.transpose(5);
+        assert_eq!((up.tonic, up.octave, up.mode), (Pitch::from(F), 4, Some(Ionian)));
+        assert_eq!(up.chromatic_positions(), scale.chromatic_positions());
+        assert_notes(&[F, G, A, Bb, C, D, E, F], up.notes());
+
+        let down = scale.transpose(-2);
+        assert_eq!((down.tonic, down.octave), (Pitch::from(As), 3));
+
+        let scale = Scale::new(Diatonic, Pitch::from(A), 4, Some(Dorian), Direction::Ascending).unwrap();
+        let up = scale.transpose(5);
+        assert_eq!((up.tonic, up.octave, up.mode), (Pitch::from(D), 5, Some(Dorian)));
+        assert_notes(&[D, E, F, G, A, B, C, D], up.notes());
+        assert_eq!(up.notes()[0].octave, 5);
+
+        let down = scale.transpose(-12);
+        assert_eq!((down.tonic, down.octave), (Pitch::from(A), 3));
+    }
+
+    #[test]
+    fn test_relative_and_parallel() {
+        let c_major = Scale::new(Diatonic, Pitch::from(C), 4, Some(Ionian), Direction::Ascending).unwrap();
+        let relative = c_major.relative_minor().unwrap();
+        assert_eq!((relative.tonic, relative.mode), (Pitch::from(A), Some(Aeolian)));
+        assert_notes(&[A, B, C, D, E, F, G, A], relative.notes());
+        let parallel = c_major.parallel_minor().unwrap();
+        assert_eq!((parallel.tonic, parallel.mode), (Pitch::from(C), Some(Aeolian)));
+        assert!(c_major.relative_major().is_none());
+        assert!(c_major.parallel_major().is_none());
+
+        let a_minor = Scale::new(Diatonic, Pitch::from(A), 4, Some(Aeolian), Direction::Ascending).unwrap();
+        let relative = a_minor.relative_major().unwrap();
+        assert_eq!((relative.tonic, relative.mode), (Pitch::from(C), Some(Ionian)));
+        let parallel = a_minor.parallel_major().unwrap();
+        assert_eq!((parallel.tonic, parallel.mode), (Pitch::from(A), Some(Ionian)));
+        assert!(a_minor.relative_minor().is_none());
+
+        let eb_major = Scale::new(Diatonic, Pitch::from(Eb), 4, Some(Ionian), Direction::Ascending).unwrap();
+        assert_eq!(eb_major.relative_minor().unwrap().tonic, Pitch::from(C));
+        let c_minor = Scale::new(ScaleType::HarmonicMinor, Pitch::from(C), 4, None, Direction::Ascending).unwrap();
+        assert_eq!(c_minor.relative_major().unwrap().tonic, Pitch::from(Eb));
+
+        let d_dorian = Scale::new(Diatonic, Pitch::from(D), 4, Some(Dorian), Direction::Ascending).unwrap();
+        assert!(d_dorian.relative_minor().is_none());
+        assert!(d_dorian.parallel_major().is_none());
+    }
+
+    #[test]
+    fn test_flat_and_sharp_keys() {
+        let scale = Scale::new(Diatonic, Pitch::from(Db), 4, Some(Ionian), Direction::Ascending).unwrap();
+        assert_notes(&[Db, Eb, F, Gb, Ab, Bb, C, Db], scale.notes());
+
+        let scale = Scale::new(Diatonic, Pitch::from(E), 4, Some(Ionian), Direction::Ascending).unwrap();
+        assert_notes(&[E, Fs, Gs, A, B, Cs, Ds, E], scale.notes());
+
+        let chords: Vec<String> = Scale::new(Diatonic, Pitch::from(Db), 4, Some(Ionian), Direction::Ascending)
+            .unwrap()
+            .diatonic_chords()
+            .iter()
+            .map(|chord| chord.notes().iter().map(|note| note.pitch.to_string()).collect::<Vec<_>>().join(" "))
+            .collect();
+        assert_eq!(chords[0], "Db F Ab");
+        assert_eq!(chords[3], "Gb Bb Db");
+        assert_eq!(chords[6], "C Eb Gb");
+    }
+
+    #[test]
+    fn test_lowest_and_highest() {
+        let scale = Scale::new(
+            ScaleType::Diatonic,
+            Pitch::from(C),
+            4,
+            Some(Mode::Ionian),
+            Direction::Descending,
+        )
+        .unwrap();
+
+        let lowest = scale.lowest().unwrap();
+        let highest = scale.highest().unwrap();
+        assert_eq!((lowest.pitch, lowest.octave), (Pitch::from(C), 3));
+        assert_eq!((highest.pitch, highest.octave), (Pitch::from(C), 4));
+    }
+
+    #[test]
+    fn test_bebop_and_harmonic_major() {
+        let bebop = Scale::from_regex("C bebop dominant").unwrap();
+        assert_eq!(bebop.chromatic_positions().len(), 8);
+        assert_eq!(bebop.chromatic_positions(), vec![0, 2, 4, 5, 7, 9, 10, 11]);
+        assert_notes(&[C, D, E, F, G, A, Bb, B, C], bebop.notes());
+        let descending = Scale::from_regex_in_direction("C bebop dominant", Direction::Descending).unwrap();
+        assert_notes(&[C, B, Bb, A, G, F, E, D, C], descending.notes());
+
+        // the major seventh is added as a passing tone between the flat seventh and the octave
+        let notes = bebop.notes();
+        assert_eq!(notes[7].pitch, Pitch::from(B));
+        assert_eq!(notes[6].pitch.into_u8() + 1, notes[7].pitch.into_u8());
+
+        let harmonic_major = Scale::from_regex("C harmonic major").unwrap();
+        assert_eq!(harmonic_major.chromatic_positions().len(), 7);
+        assert_notes(&[C, D, E, F, G, Ab, B, C], harmonic_major.notes());
+        assert!(!Mode::HarmonicMajor.is_diatonic());
+    }
+
+    #[test]
+    fn test_modes() {
+        let c_major = Scale::from_regex("C major").unwrap();
+        let modes = c_major.modes();
+        assert_eq!(modes.len(), 7);
+
+        let names: Vec<(Pitch, Option<Mode>)> = modes.iter().map(|scale| (scale.tonic, scale.mode)).collect();
+        assert_eq!(
+            names,
+            vec![
+                (Pitch::from(C), Some(Ionian)),
+                (Pitch::from(D), Some(Dorian)),
+                (Pitch::from(E), Some(Phrygian)),
+                (Pitch::from(F), Some(Lydian)),
+                (Pitch::from(G), Some(Mixolydian)),
+                (Pitch::from(A), Some(Aeolian)),
+                (Pitch::from(B), Some(Locrian)),
+            ]
+        );
+
+        assert_eq!(modes[1].notes(), Scale::from_regex("D dorian").unwrap().notes());
+
+        let a_minor = Scale::from_regex("A minor").unwrap();
+        assert_notes(&[C, D, E, F, G, A, B, C], a_minor.modes()[2].notes());
+        assert_eq!(a_minor.modes()[2].octave, 5);
+
+        let harmonic_minor = Scale::from_regex("A harmonic minor").unwrap();
+        let modes = harmonic_minor.modes();
+        assert_eq!(modes.len(), 7);
+        assert_eq!((modes[4].tonic, modes[4].mode), (Pitch::from(E), None));
+        assert_notes(&[E, F, Gs, A, B, C, D, E], modes[4].notes());
+    }
+
+    #[test]
+    fn test_chord_from_numeral() {
+        let c_major = Scale::from_regex("C major").unwrap();
+        let symbols: Vec<String> = ["I", "V", "vi", "IV"]
+            .iter()
+            .map(|numeral| c_major.chord_from_numeral(numeral).unwrap().to_string())
+            .collect();
+        assert_eq!(symbols, ["C", "G", "Am", "F"]);
+
+        let table = [
+            ("vii°", "Bdim"),
+            ("V7", "G7"),
+            ("ii7", "Dm7"),
+            ("Imaj7", "Cmaj7"),
+            ("viiø7", "Bø7"),
+            ("III+", "Eaug"),
+            ("V/V", "D"),
+            ("V7/ii", "A7"),
+            ("vii°/V", "F#dim"),
+        ];
+        for (numeral, symbol) in table.iter() {
+            assert_eq!(c_major.chord_from_numeral(numeral).unwrap().to_string(), *symbol);
+        }
+
+        let eb_major = Scale::from_regex("Eb major").unwrap();
+        assert_eq!(eb_major.chord_from_numeral("IV").unwrap().to_string(), "Ab");
+
+        assert!(matches!(c_major.chord_from_numeral("X"), Err(ScaleError::InvalidNumeral)));
+        assert!(matches!(c_major.chord_from_numeral("Vii"), Err(ScaleError::InvalidNumeral)));
+
+        // the case of the numeral decides the quality, even against the scale
+        assert_eq!(c_major.chord_from_numeral("II").unwrap().to_string(), "D");
+
+        let pentatonic = Scale::from_regex("C major pentatonic").unwrap();
+        assert_eq!(pentatonic.chord_from_numeral("v").unwrap().to_string(), "Am");
+        assert!(matches!(pentatonic.chord_from_numeral("vi"), Err(ScaleError::InvalidDegree(6))));
+        assert!(matches!(pentatonic.chord_from_numeral("vii"), Err(ScaleError::InvalidDegree(7))));
+    }
+
+    #[test]
+    fn test_from_intervals() {
+        // the augmented scale
+        let scale = Scale::from_intervals(Pitch::from(C), 4, &[3, 1, 3, 1, 3, 1]).unwrap();
+        assert_eq!(scale.scale_type, ScaleType::Custom);
+        assert_eq!(scale.mode, None);
+
+        let steps: Vec<u8> = scale.intervals().iter().map(|interval| interval.semitone_count).collect();
+        assert_eq!(steps, vec![3, 1, 3, 1, 3, 1]);
+        assert_eq!(scale.chromatic_positions(), vec![0, 3, 4, 7, 8, 11]);
+        assert_notes(&[C, Ds, E, G, Gs, B, C], scale.notes());
+        assert_eq!(scale.notes()[6].octave, 5);
+
+        let d_dorian = Scale::from_regex("D dorian").unwrap();
+        assert_eq!(d_dorian.intervals().len(), 7);
+        assert_eq!(d_dorian.intervals()[0].semitone_count, 2);
+
+        assert!(matches!(
+            Scale::from_intervals(Pitch::from(C), 4, &[2, 2, 2]),
+            Err(ScaleError::InvalidInterval)
+        ));
+        assert!(matches!(
+            Scale::from_intervals(Pitch::from(C), 4, &[0, 4, 4, 4]),
+            Err(ScaleError::InvalidInterval)
+        ));
+        assert!(Scale::new(ScaleType::Custom, Pitch::from(C), 4, None, Direction::Ascending).is_err());
+    }
+
+    #[test]
+    fn test_note_on_degree() {
+        let scale = Scale::new(Diatonic, Pitch::from(C), 4, Some(Ionian), Direction::Ascending).unwrap();
+        let note_on = |degree| scale.note_on_degree(degree).map(|note| (note.pitch, note.octave));
+        assert_eq!(note_on(1), Some((Pitch::from(C), 4)));
+        assert_eq!(note_on(5), Some((Pitch::from(G), 4)));
+        assert_eq!(note_on(8), Some((Pitch::from(C), 5)));
+        assert_eq!(note_on(10), Some((Pitch::from(E), 5)));
+        assert_eq!(note_on(0), None);
+
+        let scale = Scale::new(Diatonic, Pitch::from(F), 4, Some(Ionian), Direction::Descending).unwrap();
+        let fourth = scale.note_on_degree(4).unwrap();
+        assert_eq!((fourth.pitch, fourth.octave), (Pitch::from(Bb), 4));
+    }
+
+    #[test]
+    fn test_format_degrees() {
+        let scale = Scale::new(Diatonic, Pitch::from(C), 4, Some(Ionian), Direction::Ascending).unwrap();
+        assert_eq!(
+            scale.format_degrees(),
+            "1: C  2: D (M2)  3: E (M3)  4: F (P4)  5: G (P5)  6: A (M6)  7: B (M7)  8: C (P8)"
+        );
+
+        let scale = Scale::new(Diatonic, Pitch::from(D), 4, Some(Dorian), Direction::Descending).unwrap();
+        assert_eq!(
+            scale.format_degrees(),
+            "1: D  2: E (M2)  3: F (m3)  4: G (P4)  5: A (P5)  6: B (M6)  7: C (m7)  8: D (P8)"
+        );
+
+        let scale = Scale::new(ScaleType::Blues, Pitch::from(A), 4, None, Direction::Ascending).unwrap();
+        assert_eq!(
+            scale.format_degrees(),
+            "1: A  2: C (m3)  3: D (P4)  4: Eb (d5)  5: E (P5)  6: G (m7)  7: A (P8)"
+        );
+    }
+
+    #[test]
+    fn test_to_chord_scale() {
+        use theory::chord::{Number, Quality};
+        let chord_scale = |name: &str| {
+            let chord = Scale::from_regex(name).unwrap().to_chord_scale().unwrap();
+            (chord.root, chord.quality, chord.number)
+        };
+
+        assert_eq!(chord_scale("D dorian"), (Pitch::from(D), Quality::Minor, Number::Seventh));
+        assert_eq!(chord_scale("G mixolydian"), (Pitch::from(G), Quality::Dominant, Number::Seventh));
+        assert_eq!(chord_scale("B locrian"), (Pitch::from(B), Quality::HalfDiminished, Number::Seventh));
+        assert_eq!(chord_scale("F lydian"), (Pitch::from(F), Quality::Major, Number::Seventh));
+        assert_eq!(chord_scale("C major"), (Pitch::from(C), Quality::Major, Number::Seventh));
+        assert_eq!(
+            chord_scale("A harmonic minor"),
+            (Pitch::from(A), Quality::Minor, Number::MajorSeventh)
+        );
+        assert_eq!(
+            chord_scale("Bb bebop dominant"),
+            (Pitch::from(Bb), Quality::Dominant, Number::Seventh)
+        );
+
+        let dorian = Scale::from_regex("D3 dorian").unwrap().to_chord_scale().unwrap();
+        assert_eq!(dorian.notes()[0].octave, 3);
+        assert!(Scale::from_regex("C major pentatonic").unwrap().to_chord_scale().is_none());
+    }
+
+    #[test]
+    fn test_circle_of_fifths_position() {
+        let position = |name: &str| Scale::from_regex(name).unwrap().circle_of_fifths_position();
+        assert_eq!(position("C major"), Some(0));
+        assert_eq!(position("G major"), Some(1));
+        assert_eq!(position("F major"), Some(-1));
+        assert_eq!(position("A minor"), Some(0));
+        assert_eq!(position("E minor"), Some(1));
+        assert_eq!(position("C minor"), Some(-3));
+        assert_eq!(position("F# major"), Some(6));
+        assert_eq!(position("Gb major"), Some(-6));
+        assert_eq!(position("C# major"), Some(7));
+        assert_eq!(position("Cb major"), Some(-7));
+        assert_eq!(position("D dorian"), Some(0));
+        assert_eq!(position("E mixolydian"), Some(3));
+        assert_eq!(position("A harmonic minor"), None);
+        assert_eq!(position("C blues"), None);
+    }
+
+    #[test]
+    fn test_tonality() {
+        let tonality = |name: &str| Scale::from_regex(name).unwrap().tonality();
+        let table = [
+            ("C ionian", Tonality::Major),
+            ("C lydian", Tonality::Major),
+            ("C mixolydian", Tonality::Major),
+            ("C aeolian", Tonality::Minor),
+            ("C dorian", Tonality::Minor),
+            ("C phrygian", Tonality::Minor),
+            ("C locrian", Tonality::Minor),
+            ("C harmonic minor", Tonality::Minor),
+            ("C melodic minor", Tonality::Minor),
+            ("C harmonic major", Tonality::Major),
+            ("C major pentatonic", Tonality::Major),
+            ("C minor pentatonic", Tonality::Minor),
+            ("C blues", Tonality::Minor),
+            ("C bebop dominant", Tonality::Major),
+            ("C whole tone", Tonality::Modal),
+            ("C chromatic", Tonality::Modal),
+            ("C major #4", Tonality::Major),
+            ("C major b3", Tonality::Minor),
+        ];
+
+        for (name, expected) in table.iter() {
+            assert_eq!(tonality(name), *expected, "{}", name);
+        }
+
+        let lydian = Scale::from_regex("F lydian").unwrap();
+        assert!(lydian.is_major() && !lydian.is_minor());
+        let dorian = Scale::from_regex("D dorian").unwrap();
+        assert!(dorian.is_minor() && !dorian.is_major());
+        let whole_tone = Scale::from_regex("C whole tone").unwrap();
+        assert!(!whole_tone.is_major() && !whole_tone.is_minor());
+        let augmented = Scale::from_intervals(Pitch::from(C), 4, &[3, 1, 3, 1, 3, 1]).unwrap();
+        assert_eq!(augmented.tonality(), Tonality::Modal);
+    }
+
+    #[test]
+    fn test_descending_from_octave_zero() {
+        let scale = Scale::new(Diatonic, Pitch::from(C), 0, Some(Ionian), Direction::Descending).unwrap();
+        let notes = scale.notes();
+        assert_eq!(notes.len(), 8);
+        assert!(notes.iter().all(|note| note.octave == 0));
+
+        let scale = Scale::new(Diatonic, Pitch::from(C), 1, Some(Ionian), Direction::Descending).unwrap();
+        assert_eq!(scale.notes().last().unwrap().to_string(), "C0");
+    }
+
+    #[test]
+    fn test_step_pattern() {
+        use theory::interval::Step::{self, AugmentedSecond, Half, Whole};
+        let pattern = |name: &str| Scale::from_regex(name).unwrap().step_pattern();
+
+        assert_eq!(pattern("C major"), Some(vec![Whole, Whole, Half, Whole, Whole, Whole, Half]));
+        assert_eq!(pattern("A minor"), Some(vec![Whole, Half, Whole, Whole, Half, Whole, Whole]));
+        assert_eq!(pattern("D dorian"), Some(vec![Whole, Half, Whole, Whole, Whole, Half, Whole]));
+        assert_eq!(pattern("A harmonic minor"), Some(vec![Whole, Half, Whole, Whole, Half, AugmentedSecond, Half]));
+        assert_eq!(pattern("C whole tone"), Some(vec![Whole; 6]));
+        assert_eq!(pattern("C major pentatonic"), None);
+        assert_eq!(pattern("C blues"), None);
+
+        let major = Scale::from_steps(Pitch::from(G), "W W H W W W H").unwrap();
+        assert_notes(&[G, A, B, C, D, E, Fs, G], major.notes());
+        assert_eq!(major.octave, Scale::DEFAULT_OCTAVE);
+        let harmonic_minor = Scale::from_steps(Pitch::from(A), "W-H-W-W-H-A-H").unwrap();
+        assert_notes(&[A, B, C, D, E, F, Gs, A], harmonic_minor.notes());
+        assert_eq!(harmonic_minor.step_pattern(), pattern("A harmonic minor"));
+        let steps: Vec<Step> = harmonic_minor.step_pattern().unwrap();
+        assert_eq!(steps[5].to_string(), "AugmentedSecond");
+
+        assert!(matches!(Scale::from_steps(Pitch::from(C), "W W X"), Err(ScaleError::InvalidStep('X'))));
+        assert!(matches!(Scale::from_steps(Pitch::from(C), "W W H W W W"), Err(ScaleError::InvalidInterval)));
+    }
+
+    #[test]
+    fn test_notes_ascending_descending() {
+        let scale = Scale::from_regex("A melodic minor").unwrap();
+        let (ascending, descending) = scale.notes_ascending_descending();
+        assert_notes(&[A, B, C, D, E, Fs, Gs, A], ascending.clone());
+        assert_notes(&[A, G, F, E, D, C, B, A], descending.clone());
+        assert_eq!(ascending.first(), descending.last());
+        assert_eq!(ascending.last(), descending.first());
+        assert_eq!(descending[0].octave, 5);
+
+        let descending_scale = Scale::from_regex_in_direction("A melodic minor", Direction::Descending).unwrap();
+        assert_eq!(descending_scale.notes_ascending_descending(), (ascending, descending));
+
+        let (ascending, mut descending) = Scale::from_regex("C major").unwrap().notes_ascending_descending();
+        descending.reverse();
+        assert_eq!(ascending, descending);
+    }
+
+    #[test]
+    fn test_to_pentatonic() {
+        let pentatonic = |name: &str| Scale::from_regex(name).unwrap().to_pentatonic();
+
+        let c = pentatonic("C major").unwrap();
+        assert_eq!((c.scale_type, c.mode), (ScaleType::MajorPentatonic, Some(Mode::MajorPentatonic)));
+        assert_notes(&[C, D, E, G, A, C], c.notes());
+        assert_notes(&[A, C, D, E, G, A], pentatonic("A minor").unwrap().notes());
+        assert_notes(&[Eb, Gb, Ab, Bb, Db, Eb], pentatonic("Eb minor").unwrap().notes());
+        assert_eq!(pentatonic("G5 major").unwrap().octave, 5);
+
+        let descending = Scale::from_regex_in_direction("C major", Direction::Descending).unwrap();
+        assert_eq!(descending.to_pentatonic().unwrap().direction, Direction::Descending);
+
+        assert!(matches!(pentatonic("D dorian"), Err(ScaleError::NoPentatonic)));
+        assert!(matches!(pentatonic("A harmonic minor"), Err(ScaleError::NoPentatonic)));
+        assert!(matches!(pentatonic("C blues"), Err(ScaleError::NoPentatonic)));
+    }
 }
@@ -1,19 +1,29 @@
 mod chord {
     mod test_chord;
     mod test_number;
+    mod test_progression;
     mod test_quality;
     mod test_regex;
+    mod test_serde;
 }
 
 mod scale {
+    mod test_key_signature;
     mod test_regex;
     mod test_scale;
 }
 
 mod note {
+    mod test_note;
     mod test_pitch;
+    mod test_pitch_class_set;
 }
 
 mod interval {
+    #[allow(clippy::module_inception)]
     mod test_interval;
 }
+
+mod cli {
+    mod test_cli;
+}